@@ -0,0 +1,1846 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct PhilanthrifyFactoryProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for PhilanthrifyFactoryProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = PhilanthrifyFactoryProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        PhilanthrifyFactoryProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct PhilanthrifyFactoryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> PhilanthrifyFactoryProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        global_admin: Arg0,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&global_admin)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PhilanthrifyFactoryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PhilanthrifyFactoryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Platform admins can grant every role except PlatformAdmin, which only the global admin grants. 
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn has_role_view<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn transfer_global_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_admin: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferGlobalAdmin")
+            .argument(&new_admin)
+            .original_result()
+    }
+
+    pub fn accept_global_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptGlobalAdmin")
+            .original_result()
+    }
+
+    pub fn cancel_global_admin_transfer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelGlobalAdminTransfer")
+            .original_result()
+    }
+
+    pub fn deploy_charity<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        charity_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("deployCharity")
+            .argument(&charity_name)
+            .original_result()
+    }
+
+    /// Called by a registered charity right after it deployed a project, so the project can mint. 
+    pub fn register_project<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        project_address: Arg0,
+        project_name: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerProject")
+            .argument(&project_address)
+            .argument(&project_name)
+            .original_result()
+    }
+
+    /// Admin escape hatch for entities deployed before the registry existed. 
+    /// Projects must name their (already registered) parent charity. 
+    pub fn register_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        address: Arg0,
+        name: Arg1,
+        entity_type: Arg2,
+        opt_parent_charity: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerEntity")
+            .argument(&address)
+            .argument(&name)
+            .argument(&entity_type)
+            .argument(&opt_parent_charity)
+            .original_result()
+    }
+
+    pub fn unregister_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unregisterEntity")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_charity_template<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCharityTemplate")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    pub fn set_project_template<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProjectTemplate")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    pub fn issue_philanthrify_nft(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issuePhilanthrifyNft")
+            .original_result()
+    }
+
+    pub fn set_global_nft_collection<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        nft_token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGlobalNftCollection")
+            .argument(&nft_token_id)
+            .original_result()
+    }
+
+    pub fn grant_nft_role_to_factory(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantNftRoleToFactory")
+            .original_result()
+    }
+
+    pub fn transfer_token_manager_role<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_manager: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferTokenManagerRole")
+            .argument(&new_manager)
+            .original_result()
+    }
+
+    pub fn update_nft_uri<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        nft_token_id: Arg0,
+        nft_nonce: Arg1,
+        uri: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateNftUri")
+            .argument(&nft_token_id)
+            .argument(&nft_nonce)
+            .argument(&uri)
+            .original_result()
+    }
+
+    pub fn update_nft_attributes<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        nft_token_id: Arg0,
+        nft_nonce: Arg1,
+        attributes: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateNftAttributes")
+            .argument(&nft_token_id)
+            .argument(&nft_nonce)
+            .argument(&attributes)
+            .original_result()
+    }
+
+    pub fn set_donor_nft_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        ipfs_cid: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDonorNftMetadata")
+            .argument(&ipfs_cid)
+            .original_result()
+    }
+
+    /// Clear donor metadata CID so Attributes/Tags come only from on-chain (tags:...;traits:[...]). Call this if you want no Pinata JSON. 
+    pub fn clear_donor_nft_metadata(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearDonorNftMetadata")
+            .original_result()
+    }
+
+    pub fn set_transaction_nft_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        ipfs_cid: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTransactionNftMetadata")
+            .argument(&ipfs_cid)
+            .original_result()
+    }
+
+    /// First URI for new donor NFTs when set. Use a static image URL so we never put tier images in Assets 
+    /// (MultiversX cannot remove URIs). Current tier image is only in attributes ;image:. 
+    pub fn set_default_donor_image_uri<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        uri: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDefaultDonorImageUri")
+            .argument(&uri)
+            .original_result()
+    }
+
+    pub fn mint_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<bool>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        donor_address: Arg0,
+        token_identifier: Arg1,
+        amount: Arg2,
+        is_display_only: Arg3,
+        user_image_uri: Arg4,
+        custom_tags: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mintNft")
+            .argument(&donor_address)
+            .argument(&token_identifier)
+            .argument(&amount)
+            .argument(&is_display_only)
+            .argument(&user_image_uri)
+            .argument(&custom_tags)
+            .original_result()
+    }
+
+    /// Records many donations to the calling entity in one call, e.g. a payment processor settling 
+    /// off-chain donors. Config is loaded once and other patrons' badges are refreshed once at the end. 
+    pub fn mint_nft_batch<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, DonationItem<Env::Api>>>,
+    >(
+        self,
+        token_identifier: Arg0,
+        is_display_only: Arg1,
+        items: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mintNftBatch")
+            .argument(&token_identifier)
+            .argument(&is_display_only)
+            .argument(&items)
+            .original_result()
+    }
+
+    pub fn mint_transaction_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        entity_owner: Arg0,
+        display_amount: Arg1,
+        category: Arg2,
+        description: Arg3,
+        user_image_uri: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mintTransactionNft")
+            .argument(&entity_owner)
+            .argument(&display_amount)
+            .argument(&category)
+            .argument(&description)
+            .argument(&user_image_uri)
+            .original_result()
+    }
+
+    /// Receipt for spending the entity actually paid out of its treasury to `recipient`. 
+    pub fn mint_paid_transaction_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg6: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        entity_owner: Arg0,
+        token_identifier: Arg1,
+        amount: Arg2,
+        recipient: Arg3,
+        category: Arg4,
+        description: Arg5,
+        user_image_uri: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mintPaidTransactionNft")
+            .argument(&entity_owner)
+            .argument(&token_identifier)
+            .argument(&amount)
+            .argument(&recipient)
+            .argument(&category)
+            .argument(&description)
+            .argument(&user_image_uri)
+            .original_result()
+    }
+
+    /// Records many spending transactions of the calling entity in one call. The entity's receipt 
+    /// is created or updated once with the combined totals; the last item's category and description show. 
+    pub fn mint_transaction_nft_batch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, TransactionItem<Env::Api>>>,
+    >(
+        self,
+        entity_owner: Arg0,
+        items: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mintTransactionNftBatch")
+            .argument(&entity_owner)
+            .argument(&items)
+            .original_result()
+    }
+
+    pub fn get_global_statistics(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, GlobalStats<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGlobalStatistics")
+            .original_result()
+    }
+
+    pub fn get_donor_donations<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        donor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, DonationRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorDonations")
+            .argument(&donor)
+            .original_result()
+    }
+
+    pub fn get_donations_by_entity<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, DonationRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonationsByEntity")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_donor_nfts_for_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorNftsForEntity")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_nft_nonce(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftNonce")
+            .original_result()
+    }
+
+    pub fn get_donor_token_amount_to_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+        token_identifier: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorTokenAmountToEntity")
+            .argument(&donor)
+            .argument(&entity_id)
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn get_token_donation_total<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenDonationTotal")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn get_donation_count_to_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonationCountToEntity")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Returns the attributes string that should be on-chain for this donor+entity (for verifying tags/traits update). 
+    pub fn get_donor_nft_attributes_preview<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorNftAttributesPreview")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_entity_id_by_name<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityIdByName")
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn is_registered_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRegisteredEntity")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_charities<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        offset: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, DirectoryEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCharities")
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_projects_of_charity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        charity: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, DirectoryEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProjectsOfCharity")
+            .argument(&charity)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_entity_by_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DirectoryEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityByAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_entity_by_name<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DirectoryEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityByName")
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn get_entity_by_id<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DirectoryEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityById")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_charity_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCharityCount")
+            .original_result()
+    }
+
+    pub fn get_project_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProjectCount")
+            .original_result()
+    }
+
+    pub fn get_project_template(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProjectTemplate")
+            .original_result()
+    }
+
+    pub fn get_charity_template(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCharityTemplate")
+            .original_result()
+    }
+
+    /// Metadata with is_on_contract read from the factory's live balance, so badges sent back 
+    /// by plain transfer are reported correctly too. 
+    pub fn get_nft_metadata<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nft_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, NftMetadataRecord<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftMetadata")
+            .argument(&nft_nonce)
+            .original_result()
+    }
+
+    /// Donor badges split into those held by the factory and those withdrawn to a wallet. 
+    pub fn get_donor_badge_locations<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        donor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedVec<Env::Api, u64>, ManagedVec<Env::Api, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorBadgeLocations")
+            .argument(&donor)
+            .original_result()
+    }
+
+    /// One-off migration for badges minted before the donor_badges index existed: indexes every donor 
+    /// badge among `count` nonces starting at `from_nonce`. Run it over 1..=getNftNonce in slices; 
+    /// receipts and already indexed badges are skipped. Returns how many badges were added. 
+    pub fn backfill_donor_badges<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        from_nonce: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("backfillDonorBadges")
+            .argument(&from_nonce)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_donor_nft_metadata_for_entity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<NftMetadataRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorNftMetadataForEntity")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_transaction_nft_for_entity<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<NftMetadataRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTransactionNftForEntity")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Receipts per transaction instead of one aggregated receipt; applies to transactions recorded afterwards. 
+    pub fn set_entity_receipt_mode<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ReceiptMode>,
+    >(
+        self,
+        entity_id: Arg0,
+        mode: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEntityReceiptMode")
+            .argument(&entity_id)
+            .argument(&mode)
+            .original_result()
+    }
+
+    pub fn get_entity_receipt_mode<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReceiptMode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityReceiptMode")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_spending_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingCount")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Spending the entity declared without paying it from its treasury (display-only, in EGLD). 
+    pub fn get_entity_display_spending<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityDisplaySpending")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Spending paid out of the entity's treasury, per token; matches the entity's getTotalSpent. 
+    pub fn get_entity_paid_spending<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityPaidSpending")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Spending records in the order they were recorded. 
+    pub fn get_spending_records<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        entity_id: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, SpendingRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingRecords")
+            .argument(&entity_id)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_spending_by_category<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        entity_id: Arg0,
+        category: Arg1,
+        offset: Arg2,
+        limit: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, SpendingRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingByCategory")
+            .argument(&entity_id)
+            .argument(&category)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// Marks a spending record for review; flagging it again replaces the reason. 
+    pub fn flag_spending_record<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        entity_id: Arg0,
+        record_id: Arg1,
+        reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flagSpendingRecord")
+            .argument(&entity_id)
+            .argument(&record_id)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn clear_spending_flag<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+        record_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearSpendingFlag")
+            .argument(&entity_id)
+            .argument(&record_id)
+            .original_result()
+    }
+
+    /// Open auditor flags on an entity's spending ledger, in no particular order. 
+    pub fn get_spending_flags<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, SpendingFlag<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingFlags")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Spending records with `start <= timestamp <= end`, skipping the first `offset` of them. 
+    pub fn get_spending_in_time_range<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<usize>,
+    >(
+        self,
+        entity_id: Arg0,
+        start_timestamp: Arg1,
+        end_timestamp: Arg2,
+        offset: Arg3,
+        limit: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, SpendingRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingInTimeRange")
+            .argument(&entity_id)
+            .argument(&start_timestamp)
+            .argument(&end_timestamp)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn retrieve_donor_nft<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retrieveDonorNft")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Sends the caller's on-contract badges in one multi-transfer, optionally only for the given entities. 
+    /// Soulbound and frozen-custody badges are skipped; frozen ones go through retrieveDonorNft. 
+    pub fn retrieve_all_donor_nfts<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        max_count: Arg0,
+        entity_ids: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retrieveAllDonorNfts")
+            .argument(&max_count)
+            .argument(&entity_ids)
+            .original_result()
+    }
+
+    /// Deposit a retrieved badge back on the factory so it resumes dynamic updates. 
+    /// Frozen badges need unfreezeDonorNft first. 
+    pub fn return_donor_nft(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("returnDonorNft")
+            .original_result()
+    }
+
+    /// Lets a donor holding a frozen badge send it back with returnDonorNft. 
+    pub fn unfreeze_donor_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        nft_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unfreezeDonorNft")
+            .argument(&donor)
+            .argument(&nft_nonce)
+            .original_result()
+    }
+
+    /// EGLD (in its smallest unit) that one whole token counts as towards tiers and the leaderboard. 
+    /// Applies to donations made afterwards; set the token's decimals first. 
+    pub fn set_token_ranking_rate<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        egld_per_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenRankingRate")
+            .argument(&token)
+            .argument(&egld_per_token)
+            .original_result()
+    }
+
+    pub fn clear_token_ranking_rate<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearTokenRankingRate")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn get_token_ranking_rate<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenRankingRate")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Everything the donor gave the entity, per token: (token, total). 
+    pub fn get_donor_token_totals<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorTokenTotals")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Decimals and shown precision for totals and receipt amounts (default 18 and 4). 
+    pub fn set_amount_format<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        decimals: Arg0,
+        precision: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAmountFormat")
+            .argument(&decimals)
+            .argument(&precision)
+            .original_result()
+    }
+
+    pub fn set_token_decimals<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        token: Arg0,
+        decimals: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenDecimals")
+            .argument(&token)
+            .argument(&decimals)
+            .original_result()
+    }
+
+    pub fn get_amount_format(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AmountFormat> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAmountFormat")
+            .original_result()
+    }
+
+    pub fn get_token_decimals<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenDecimals")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Platform-wide badge custody, used by entities without their own. 
+    pub fn set_badge_custody<
+        Arg0: ProxyArg<BadgeCustody>,
+    >(
+        self,
+        custody: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBadgeCustody")
+            .argument(&custody)
+            .original_result()
+    }
+
+    pub fn set_entity_badge_custody<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BadgeCustody>,
+    >(
+        self,
+        entity_id: Arg0,
+        custody: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEntityBadgeCustody")
+            .argument(&entity_id)
+            .argument(&custody)
+            .original_result()
+    }
+
+    pub fn clear_entity_badge_custody<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearEntityBadgeCustody")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Badge custody in effect for an entity (its own, the platform-wide one, or Transferable by default). 
+    pub fn get_entity_badge_custody<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BadgeCustody> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityBadgeCustody")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn set_tier_image_uri<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        tier: Arg0,
+        uri: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTierImageUri")
+            .argument(&tier)
+            .argument(&uri)
+            .original_result()
+    }
+
+    pub fn add_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+        tag: Arg1,
+        image_uri: Arg2,
+        min_donations: Arg3,
+        min_amount: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTier")
+            .argument(&name)
+            .argument(&tag)
+            .argument(&image_uri)
+            .argument(&min_donations)
+            .argument(&min_amount)
+            .original_result()
+    }
+
+    pub fn update_tier<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        tier_level: Arg0,
+        name: Arg1,
+        tag: Arg2,
+        image_uri: Arg3,
+        min_donations: Arg4,
+        min_amount: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateTier")
+            .argument(&tier_level)
+            .argument(&name)
+            .argument(&tag)
+            .argument(&image_uri)
+            .argument(&min_donations)
+            .argument(&min_amount)
+            .original_result()
+    }
+
+    pub fn remove_last_tier(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeLastTier")
+            .original_result()
+    }
+
+    /// Replaces the tier table of one entity; the entity then ignores the platform-wide table. 
+    pub fn set_entity_tiers<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue5<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, u64, BigUint<Env::Api>>>>,
+    >(
+        self,
+        entity_id: Arg0,
+        tiers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEntityTiers")
+            .argument(&entity_id)
+            .argument(&tiers)
+            .original_result()
+    }
+
+    pub fn clear_entity_tiers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearEntityTiers")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Platform-wide tier policy, used by entities without their own. 
+    pub fn set_tier_policy<
+        Arg0: ProxyArg<TierPolicy>,
+    >(
+        self,
+        policy: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTierPolicy")
+            .argument(&policy)
+            .original_result()
+    }
+
+    pub fn set_entity_tier_policy<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<TierPolicy>,
+    >(
+        self,
+        entity_id: Arg0,
+        policy: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEntityTierPolicy")
+            .argument(&entity_id)
+            .argument(&policy)
+            .original_result()
+    }
+
+    pub fn clear_entity_tier_policy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearEntityTierPolicy")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Tier policy in effect for an entity (its own, the platform-wide one, or DonationCount by default). 
+    pub fn get_entity_tier_policy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TierPolicy> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityTierPolicy")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_tiers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TierConfig<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTiers")
+            .original_result()
+    }
+
+    /// Tier table in effect for an entity (its override, or the platform-wide table). 
+    pub fn get_entity_tiers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TierConfig<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEntityTiers")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn set_patron_image_uri<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        patron_type: Arg0,
+        uri: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPatronImageUri")
+            .argument(&patron_type)
+            .argument(&uri)
+            .original_result()
+    }
+
+    pub fn get_project_patrons<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PatronRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProjectPatrons")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_donor_patron_rank_view<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorPatronRank")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Rank among all donors of the entity, including those below the leaderboard. Ranking below the board 
+    /// iterates every donor, so it is only answered for entities with up to 1000 donors (None beyond that). 
+    pub fn get_donor_leaderboard_rank<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorLeaderboardRank")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn get_leaderboard_size_view<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        entity_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLeaderboardSize")
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    /// Shrinking drops the lowest entries; growing re-admits the best donors below the board from their totals. 
+    /// Growing walks every donor of the entity, so it is rejected above 1000 donors. 
+    pub fn set_leaderboard_size<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        entity_id: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLeaderboardSize")
+            .argument(&entity_id)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Streaks across all of the donor's donations, whatever the entity. 
+    pub fn get_donor_recurring_pattern<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        donor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RecurringPattern> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorRecurringPattern")
+            .argument(&donor)
+            .original_result()
+    }
+
+    /// Streaks of the donor's donations to one entity, as shown on that entity's badge. 
+    pub fn get_donor_entity_recurring_pattern<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        donor: Arg0,
+        entity_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RecurringPattern> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonorEntityRecurringPattern")
+            .argument(&donor)
+            .argument(&entity_id)
+            .original_result()
+    }
+
+    pub fn global_admin_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGlobalAdmin")
+            .original_result()
+    }
+
+    pub fn pending_global_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingGlobalAdmin")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn role_members<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleMembers")
+            .argument(&role)
+            .original_result()
+    }
+
+    /// Set once the system contract confirmed the freeze of a retrieved badge, cleared on unfreeze 
+    pub fn badge_frozen<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nft_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isBadgeFrozen")
+            .argument(&nft_nonce)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    PlatformAdmin,
+    NftManager,
+    Auditor,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct DonationItem<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub donor: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+    pub user_image_uri: ManagedBuffer<Api>,
+    pub tags: ManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TransactionItem<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub display_amount: BigUint<Api>,
+    pub category: ManagedBuffer<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub user_image_uri: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct GlobalStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub total_donations_amount: BigUint<Api>,
+    pub total_donations_count: u64,
+    pub total_nfts_minted: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DonationRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+    pub timestamp: u64,
+    pub entity_id: u64,
+    pub entity_name: ManagedBuffer<Api>,
+    pub entity_type: ManagedBuffer<Api>,
+    pub nft_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DirectoryEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub address: ManagedAddress<Api>,
+    pub name: ManagedBuffer<Api>,
+    pub entity_type: ManagedBuffer<Api>,
+    pub parent: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct NftMetadataRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub nft_nonce: u64,
+    pub donor_address: ManagedAddress<Api>,
+    pub entity_id: u64,
+    pub entity_name: ManagedBuffer<Api>,
+    pub entity_type: ManagedBuffer<Api>,
+    pub donation_count: u64,
+    pub tier_level: u64,
+    pub total_amount: BigUint<Api>,
+    pub last_token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub last_amount: BigUint<Api>,
+    pub last_updated: u64,
+    pub is_on_contract: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptMode {
+    Aggregated,
+    PerTransaction,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct SpendingRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub entity_id: u64,
+    pub amount: BigUint<Api>,
+    pub category: ManagedBuffer<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub timestamp: u64,
+    pub receipt_nonce: u64,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub recipient: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct SpendingFlag<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub record_id: u64,
+    pub auditor: ManagedAddress<Api>,
+    pub reason: ManagedBuffer<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub struct AmountFormat {
+    pub decimals: u32,
+    pub precision: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeCustody {
+    Transferable,
+    RetrievableFrozen,
+    Soulbound,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum TierPolicy {
+    DonationCount,
+    TotalAmount,
+    Weighted {
+        count_weight_bps: u64,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TierConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name: ManagedBuffer<Api>,
+    pub tag: ManagedBuffer<Api>,
+    pub image_uri: ManagedBuffer<Api>,
+    pub min_donations: u64,
+    pub min_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct PatronRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub donor_address: ManagedAddress<Api>,
+    pub total_amount: BigUint<Api>,
+    pub patron_rank: u64,
+    pub since_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct RecurringPattern {
+    pub monthly_streak: u64,
+    pub quarterly_streak: u64,
+    pub last_donation_month: u64,
+    pub last_donation_quarter: u64,
+}
@@ -5,6 +5,37 @@ extern crate alloc;
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod factory_proxy;
+pub mod project_proxy;
+
+const MAX_BATCH_DONATIONS: usize = 100;
+const GAS_PER_BATCH_DONATION: u64 = 5_000_000;  // Rough cost of one badge mint or update on the factory
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Treasurer,  // Withdrawals and spending transactions
+    Editor,     // Accepted tokens and donation settings
+    Processor,  // Settles batched off-chain donations
+}
+
+/// What happens when spending takes a category over its budget.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    Warn,    // Record it and emit budget_exceeded (default)
+    Reject,  // Fail the transaction
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct CategoryBudget<M: ManagedTypeApi> {
+    pub category: ManagedBuffer<M>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,  // The budget's token; spent is in this token too
+    pub budget: BigUint<M>,  // 0 = no cap
+    pub spent: BigUint<M>,
+}
+
 #[multiversx_sc::contract]
 pub trait PhilanthrifyCharity {
     #[init]
@@ -40,6 +71,65 @@ pub trait PhilanthrifyCharity {
         );
     }
 
+    fn only_owner_or_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        if self.role_members(role).contains(&caller) {
+            return;
+        }
+        self.only_owner();
+    }
+
+    // ============================================================
+    // PAUSE - Local pause, plus the factory's global pause
+    // ============================================================
+
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.only_owner();
+        require!(!self.paused().get(), "Already paused");
+        self.paused().set(true);
+        self.paused_event(&self.blockchain().get_caller());
+    }
+
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.only_owner();
+        require!(self.paused().get(), "Not paused");
+        self.paused().set(false);
+        self.unpaused_event(&self.blockchain().get_caller());
+    }
+
+    /// Checks the factory flag directly so a platform-wide pause fails here before any cross-contract call.
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Charity is paused");
+        let factory = self.factory_address().get();
+        require!(!self.factory_paused(factory).get(), "Platform is paused");
+    }
+
+    // ============================================================
+    // ROLES
+    // ============================================================
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.only_owner();
+        require!(!address.is_zero(), "Invalid address");
+        require!(self.role_members(role).insert(address.clone()), "Address already has role");
+        self.role_granted(role, &address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.only_owner();
+        require!(self.role_members(role).swap_remove(&address), "Address does not have role");
+        self.role_revoked(role, &address);
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        self.role_members(role).contains(&address)
+    }
+
     // ============================================================
     // TRANSACTION - MINTS NFT TO TRACK CHARITY SPENDING
     // ============================================================
@@ -51,10 +141,12 @@ pub trait PhilanthrifyCharity {
         description: ManagedBuffer,
         user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
     ) {
-        self.only_owner();
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Treasurer);
 
         // 0 EGLD - only gas fees paid. display_amount is for NFT display only
         require!(display_amount > BigUint::zero(), "Display amount must be > 0");
+        self.record_category_spending(&category, &EgldOrEsdtTokenIdentifier::egld(), &display_amount);
 
         let charity_name = self.charity_name().get();
         let owner = self.owner().get();
@@ -63,19 +155,47 @@ pub trait PhilanthrifyCharity {
         // Call factory to mint transaction NFT
         self.tx()
             .to(&factory)
-            .raw_call("mintTransactionNft")
-            .argument(&owner)
-            .argument(&display_amount)  // Display amount only
-            .argument(&charity_name)
-            .argument(&ManagedBuffer::from("charity"))
-            .argument(&category)
-            .argument(&description)
-            .argument(&user_image_uri)  // Empty string if not provided
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_transaction_nft(&owner, &display_amount, &category, &description, &user_image_uri)
             .sync_call();
 
         self.transaction_event(&charity_name, &display_amount, &category, &description);
     }
 
+    /// Pays a supplier or beneficiary from the treasury and mints the spending receipt,
+    /// so the spending ledger matches money that actually left the charity.
+    #[endpoint(paidTransactionForCharity)]
+    fn paid_transaction_for_charity(
+        &self,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        recipient: ManagedAddress,
+        category: ManagedBuffer,
+        description: ManagedBuffer,
+        user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
+    ) {
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Treasurer);
+        require!(amount > 0u32, "Amount must be > 0");
+        require!(!recipient.is_zero(), "Invalid recipient address");
+        require!(amount <= self.get_available_balance(token_identifier.clone()), "Insufficient available balance");
+        self.record_category_spending(&category, &token_identifier, &amount);
+        self.total_spent(&token_identifier).update(|total| *total += &amount);
+
+        let charity_name = self.charity_name().get();
+        let owner = self.owner().get();
+        let factory = self.factory_address().get();
+        self.tx()
+            .to(&factory)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_paid_transaction_nft(&owner, &token_identifier, &amount, &recipient, &category, &description, &user_image_uri)
+            .sync_call();
+
+        self.tx().to(&recipient).egld_or_single_esdt(&token_identifier, 0, &amount).transfer();
+        self.transaction_event(&charity_name, &amount, &category, &description);
+        self.spending_paid_event(&recipient, &token_identifier, &amount, &category);
+    }
+
     #[allow_multiple_var_args]
     #[endpoint(batchTransactionForCharity)]
     fn batch_transaction_for_charity(
@@ -85,7 +205,8 @@ pub trait PhilanthrifyCharity {
         categories: MultiValueEncoded<ManagedBuffer>,
         descriptions: MultiValueEncoded<ManagedBuffer>,
     ) {
-        self.only_owner();
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Treasurer);
         require!(num_transactions > 0, "Number of transactions must be > 0");
         
         let mut amounts_vec: ManagedVec<Self::Api, BigUint> = ManagedVec::new();
@@ -114,111 +235,281 @@ pub trait PhilanthrifyCharity {
         let owner = self.owner().get();
         let factory = self.factory_address().get();
 
+        let mut items: MultiValueEncoded<factory_proxy::TransactionItem<Self::Api>> = MultiValueEncoded::new();
         for i in 0..num_transactions {
             let display_amount = amounts_vec.get(i as usize);
             let category = categories_vec.get(i as usize);
             let description = descriptions_vec.get(i as usize);
             
             require!(*display_amount > BigUint::zero(), "Display amount must be > 0");
+            self.record_category_spending(&category, &EgldOrEsdtTokenIdentifier::egld(), &display_amount);
             
-            self.tx()
-                .to(&factory)
-                .raw_call("mintTransactionNft")
-                .argument(&owner)
-                .argument(&display_amount)
-                .argument(&charity_name)
-                .argument(&ManagedBuffer::from("charity"))
-                .argument(&category)
-                .argument(&description)
-                .argument(&ManagedBuffer::new())  // Empty string for batch (no user image)
-                .sync_call();
-
             self.batch_transaction_event(&charity_name, i + 1, num_transactions, &display_amount, &category, &description);
+            items.push(factory_proxy::TransactionItem {
+                display_amount: display_amount.clone(),
+                category: category.clone(),
+                description: description.clone(),
+                user_image_uri: ManagedBuffer::new(),  // No user image in batch
+            });
         }
+
+        // One factory call updates the receipt once for the whole batch
+        self.tx()
+            .to(&factory)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_transaction_nft_batch(&owner, items)
+            .sync_call();
+    }
+
+    // ============================================================
+    // SPENDING CATEGORIES - Optional catalogue with budget caps
+    // ============================================================
+
+    /// Adds a category to the catalogue or changes its budget (0 = no cap). The budget only caps
+    /// spending in `token_identifier` (display-only spending counts as EGLD); other tokens are tracked uncapped.
+    /// Once the catalogue has entries, spending must use one of them.
+    #[endpoint(setSpendingCategory)]
+    fn set_spending_category(&self, category: ManagedBuffer, token_identifier: EgldOrEsdtTokenIdentifier, budget: BigUint) {
+        self.only_owner();
+        require!(!category.is_empty(), "Category cannot be empty");
+        require!(token_identifier.is_valid(), "Invalid token identifier");
+        self.spending_categories().insert(category.clone());
+        self.category_budget_token(&category).set(&token_identifier);
+        self.category_budget(&category).set(&budget);
+    }
+
+    /// Running totals are kept in case the category is added back.
+    #[endpoint(removeSpendingCategory)]
+    fn remove_spending_category(&self, category: ManagedBuffer) {
+        self.only_owner();
+        require!(self.spending_categories().swap_remove(&category), "Unknown spending category");
+        self.category_budget(&category).clear();
+        self.category_budget_token(&category).clear();
+    }
+
+    #[endpoint(setBudgetPolicy)]
+    fn set_budget_policy(&self, policy: BudgetPolicy) {
+        self.only_owner();
+        self.budget_policy().set(policy);
+    }
+
+    /// Budget vs spent, in the budget's token, for every catalogue category.
+    #[view(getCategoryBudgets)]
+    fn get_category_budgets(&self) -> MultiValueEncoded<CategoryBudget<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for category in self.spending_categories().iter() {
+            let token_identifier = self.category_budget_token(&category).get();
+            let budget = self.category_budget(&category).get();
+            let spent = self.category_spent(&category, &token_identifier).get();
+            result.push(CategoryBudget { category, token_identifier, budget, spent });
+        }
+        result
+    }
+
+    fn record_category_spending(&self, category: &ManagedBuffer, token_identifier: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let categories = self.spending_categories();
+        require!(categories.is_empty() || categories.contains(category), "Unknown spending category");
+
+        let spent = self.category_spent(category, token_identifier).get() + amount;
+        let budget = self.category_budget(category).get();
+        if budget > 0u32 && spent > budget && self.category_budget_token(category).get() == *token_identifier {
+            require!(self.budget_policy().get() == BudgetPolicy::Warn, "Category budget exceeded");
+            self.budget_exceeded_event(category, &budget, &spent);
+        }
+        self.category_spent(category, token_identifier).set(&spent);
     }
 
     // ============================================================
     // DONATION FUNCTIONS
     // ============================================================
 
+    #[payable("*")]
     #[endpoint(donateToCharity)]
     fn donate_to_charity(
         &self,
-        display_amount: BigUint,
         user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
         custom_tags: MultiValueEncoded<ManagedBuffer>,
     ) {
-        // 0 EGLD - only gas fees paid. display_amount is for NFT display only
+        self.require_not_paused();
+        // Real donation: EGLD or an accepted ESDT stays in the charity treasury until the owner withdraws it
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0u32, "Donation amount must be > 0");
+        require!(payment.token_nonce == 0, "Only fungible tokens can be donated");
+        if let Some(token_id) = payment.token_identifier.as_esdt_option() {
+            require!(self.accepted_tokens().contains(&token_id), "Token not accepted");
+        }
+
         let caller = self.blockchain().get_caller();
+        self.received_tokens().insert(payment.token_identifier.clone());
+        self.total_received(&payment.token_identifier).update(|total| *total += &payment.amount);
+
+        self.mint_donation_nft(&caller, &payment.token_identifier, &payment.amount, false, &user_image_uri, custom_tags);
+    }
+
+    /// Off-chain (fiat) donations recorded for `donor`: no value moves, display_amount is for NFT display only.
+    /// Disabled unless the owner opts in via setDisplayOnlyDonations; only the owner or a processor may record them.
+    #[endpoint(donateDisplayOnly)]
+    fn donate_display_only(
+        &self,
+        donor: ManagedAddress,
+        display_amount: BigUint,
+        user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
+        custom_tags: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Processor);
+        require!(self.display_only_donations_enabled().get(), "Display-only donations are disabled");
+        require!(display_amount > 0u32, "Display amount must be > 0");
+
+        self.mint_donation_nft(&donor, &EgldOrEsdtTokenIdentifier::egld(), &display_amount, true, &user_image_uri, custom_tags);
+    }
+
+    fn mint_donation_nft(
+        &self,
+        donor: &ManagedAddress,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+        is_display_only: bool,
+        user_image_uri: &ManagedBuffer,
+        custom_tags: MultiValueEncoded<ManagedBuffer>,
+    ) {
         let factory = self.factory_address().get();
         let charity_name = self.charity_name().get();
 
-        let mut call = self.tx()
+        self.tx()
             .to(&factory)
-            .raw_call("mintNft")
-            .argument(&caller)
-            .argument(&display_amount)  // Display amount only
-            .argument(&charity_name)
-            .argument(&ManagedBuffer::from("charity"))
-            .argument(&user_image_uri);  // user_image_uri before custom_tags
-
-        for tag in custom_tags.into_iter() {
-            call = call.argument(&tag);
-        }
-
-        call.sync_call();
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_nft(donor, token_identifier, amount, is_display_only, user_image_uri, custom_tags)
+            .sync_call();
 
-        self.donation_event(&caller, &display_amount, &charity_name);
+        self.donation_event(donor, amount, &charity_name);
     }
 
+    /// Settles off-chain (fiat) donations for many donors in one factory call; amounts are for NFT display only.
     #[endpoint(batchDonateToCharity)]
-    fn batch_donate_to_charity(&self, num_donations: u64, display_amount_per_donation: BigUint, custom_tags: MultiValueEncoded<ManagedBuffer>) {
-        // 0 EGLD - only gas fees paid. display_amount is for NFT display only
-        require!(num_donations > 0 && num_donations <= 100, "Batch must be 1-100");
-        require!(display_amount_per_donation > BigUint::zero(), "Display amount must be > 0");
+    fn batch_donate_to_charity(&self, donations: MultiValueEncoded<factory_proxy::DonationItem<Self::Api>>) {
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Processor);
+        require!(self.display_only_donations_enabled().get(), "Display-only donations are disabled");
+        let donation_count = donations.len();
+        require!(donation_count > 0 && donation_count <= MAX_BATCH_DONATIONS, "Batch must be 1-100");
+        require!(
+            self.blockchain().get_gas_left() >= donation_count as u64 * GAS_PER_BATCH_DONATION,
+            "Not enough gas for batch size"
+        );
+
+        let items = donations.to_vec();
+        let mut total_amount = BigUint::zero();
+        for item in items.iter() {
+            require!(item.amount > 0u32, "Display amount must be > 0");
+            total_amount += &item.amount;
+        }
 
         let caller = self.blockchain().get_caller();
         let factory = self.factory_address().get();
         let charity_name = self.charity_name().get();
 
-        let mut tags_vec: ManagedVec<Self::Api, ManagedBuffer> = ManagedVec::new();
-        for tag in custom_tags.into_iter() {
-            tags_vec.push(tag);
+        self.tx()
+            .to(&factory)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_nft_batch(EgldOrEsdtTokenIdentifier::egld(), true, donations)
+            .sync_call();
+
+        for item in items.iter() {
+            self.donation_event(&item.donor, &item.amount, &charity_name);
         }
+        self.batch_event(&caller, donation_count, &total_amount, &charity_name);
+    }
 
-        for i in 0..num_donations {
-            let mut call = self.tx()
-                .to(&factory)
-                .raw_call("mintNft")
-                .argument(&caller)
-                .argument(&display_amount_per_donation)  // Display amount only
-                .argument(&charity_name)
-                .argument(&ManagedBuffer::from("charity"));
+    #[endpoint(addAcceptedToken)]
+    fn add_accepted_token(&self, token_id: TokenIdentifier) {
+        self.only_owner_or_role(Role::Editor);
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
+        self.accepted_tokens().insert(token_id);
+    }
 
-            for tag in tags_vec.iter() {
-                call = call.argument(&tag);
-            }
+    #[endpoint(removeAcceptedToken)]
+    fn remove_accepted_token(&self, token_id: TokenIdentifier) {
+        self.only_owner_or_role(Role::Editor);
+        self.accepted_tokens().swap_remove(&token_id);
+    }
+
+    #[endpoint(setDisplayOnlyDonations)]
+    fn set_display_only_donations(&self, enabled: bool) {
+        self.only_owner_or_role(Role::Editor);
+        self.display_only_donations_enabled().set(enabled);
+    }
 
-            call.sync_call();
+    // ============================================================
+    // TREASURY - Owner withdraws received donations
+    // ============================================================
 
-            self.batch_event(&caller, i + 1, num_donations, &display_amount_per_donation, &charity_name);
+    #[endpoint(withdraw)]
+    fn withdraw(
+        &self,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        opt_to: OptionalValue<ManagedAddress>,
+    ) {
+        self.only_owner_or_role(Role::Treasurer);
+        require!(amount > 0u32, "Amount must be > 0");
+        require!(amount <= self.get_available_balance(token_identifier.clone()), "Insufficient available balance");
+
+        let to = match opt_to {
+            OptionalValue::Some(address) => address,
+            OptionalValue::None => self.blockchain().get_caller(),
+        };
+        require!(!to.is_zero(), "Invalid recipient address");
+
+        self.total_withdrawn(&token_identifier).update(|total| *total += &amount);
+        self.tx().to(&to).egld_or_single_esdt(&token_identifier, 0, &amount).transfer();
+
+        self.withdrawal_event(&to, &token_identifier, &amount);
+    }
+
+    /// Received minus withdrawn minus paid out as spending.
+    #[view(getAvailableBalance)]
+    fn get_available_balance(&self, token_identifier: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.total_received(&token_identifier).get()
+            - self.total_withdrawn(&token_identifier).get()
+            - self.total_spent(&token_identifier).get()
+    }
+
+    /// Per-token accounting for every token ever donated: (token, received, withdrawn, available).
+    /// Available is also net of spending; see getTotalSpent.
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> MultiValueEncoded<MultiValue4<EgldOrEsdtTokenIdentifier, BigUint, BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_identifier in self.received_tokens().iter() {
+            let received = self.total_received(&token_identifier).get();
+            let withdrawn = self.total_withdrawn(&token_identifier).get();
+            let available = self.get_available_balance(token_identifier.clone());
+            result.push((token_identifier, received, withdrawn, available).into());
         }
+        result
     }
 
     #[endpoint(deployProject)]
     fn deploy_project(&self, project_name: ManagedBuffer) -> ManagedAddress {
+        self.require_not_paused();
         self.only_owner();
 
         let project_template = self.project_template().get();
         require!(!project_template.is_zero(), "Project template not set. Use setProjectTemplate or deploy charity with template set in factory.");
 
         let factory_address = self.factory_address().get();
+        // Names are unique platform-wide; fail before paying for the deploy (the factory enforces it again on register)
+        require!(
+            self.factory_entity_id_by_name(factory_address.clone(), &project_name).is_empty(),
+            "Entity name already taken"
+        );
         let charity_address = self.blockchain().get_sc_address();
-        let charity_owner = self.owner().get(); // This is the admin address
 
-        let new_project: ManagedAddress = self
+        // The project reads this charity's owner as its admin, so no admin address is passed
+        let new_project = self
             .tx()
-            .raw_deploy()
+            .typed(project_proxy::PhilanthrifyProjectProxy)
+            .init(&project_name, &charity_address, &factory_address)
             .from_source(project_template)
             .code_metadata(
                 CodeMetadata::PAYABLE
@@ -226,37 +517,62 @@ pub trait PhilanthrifyCharity {
                     | CodeMetadata::UPGRADEABLE
                     | CodeMetadata::READABLE,
             )
-            .argument(&project_name)
-            .argument(&charity_address)
-            .argument(&factory_address)
-            .argument(&charity_owner)  // Pass charity owner (admin) as global_admin, not charity address
             .gas(15_000_000)
-            .returns(ReturnsNewAddress)
-            .sync_call()
-            .into();
+            .returns(ReturnsNewManagedAddress)
+            .sync_call();
+
+        // Register the project with the factory so it is authorized to mint NFTs
+        self.tx()
+            .to(&factory_address)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .register_project(&new_project, &project_name)
+            .sync_call();
 
         self.project_deployed_event(&project_name, &new_project);
         new_project
     }
 
+    #[endpoint(setProjectTemplate)]
+    fn set_project_template(&self, project_template: ManagedAddress) {
+        self.only_owner();
+        require!(!project_template.is_zero(), "Invalid template address");
+        self.project_template().set(project_template);
+    }
+
+    // ============================================================
+    // OWNERSHIP TRANSFER - Propose, then the new owner accepts
+    // ============================================================
+
     #[endpoint(transferOwnership)]
     fn transfer_ownership(&self, new_owner: ManagedAddress) {
         self.only_owner();
         require!(!new_owner.is_zero(), "Invalid owner address");
-        self.owner().set(&new_owner);
+        let current_owner = self.owner().get();
+        require!(new_owner != current_owner, "Address is already owner");
+
+        self.pending_owner().set(&new_owner);
+        self.ownership_transfer_started(&current_owner, &new_owner);
     }
 
-    #[endpoint(setOwner)]
-    fn set_owner(&self, new_owner: ManagedAddress) {
-        self.only_owner();
+    #[endpoint(acceptOwnership)]
+    fn accept_ownership(&self) {
+        let pending_mapper = self.pending_owner();
+        require!(!pending_mapper.is_empty(), "No pending owner");
+        let new_owner = pending_mapper.take();
+        require!(self.blockchain().get_caller() == new_owner, "Only pending owner allowed");
+
+        let previous_owner = self.owner().get();
         self.owner().set(&new_owner);
+        self.ownership_transferred(&previous_owner, &new_owner);
     }
 
-    #[endpoint(setProjectTemplate)]
-    fn set_project_template(&self, project_template: ManagedAddress) {
+    #[endpoint(cancelOwnershipTransfer)]
+    fn cancel_ownership_transfer(&self) {
         self.only_owner();
-        require!(!project_template.is_zero(), "Invalid template address");
-        self.project_template().set(project_template);
+        let pending_mapper = self.pending_owner();
+        require!(!pending_mapper.is_empty(), "No pending owner");
+        let pending_owner = pending_mapper.take();
+        self.ownership_transfer_cancelled(&pending_owner);
     }
 
     // ============================================================
@@ -280,13 +596,41 @@ pub trait PhilanthrifyCharity {
         #[indexed] entity: &ManagedBuffer,
     );
 
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("unpaused")]
+    fn unpaused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("ownership_transfer_started")]
+    fn ownership_transfer_started(&self, #[indexed] current: &ManagedAddress, #[indexed] pending: &ManagedAddress);
+
+    #[event("ownership_transfer_cancelled")]
+    fn ownership_transfer_cancelled(&self, #[indexed] pending: &ManagedAddress);
+
+    #[event("ownership_transferred")]
+    fn ownership_transferred(&self, #[indexed] previous: &ManagedAddress, #[indexed] new: &ManagedAddress);
+
+    #[event("role_granted")]
+    fn role_granted(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("withdrawal_event")]
+    fn withdrawal_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
     #[event("batch_event")]
     fn batch_event(
         &self,
         #[indexed] caller: &ManagedAddress,
-        #[indexed] current: u64,
-        #[indexed] total: u64,
-        #[indexed] amount_per: &BigUint,
+        #[indexed] donation_count: usize,
+        #[indexed] total_amount: &BigUint,
         #[indexed] entity: &ManagedBuffer,
     );
 
@@ -297,6 +641,23 @@ pub trait PhilanthrifyCharity {
         #[indexed] address: &ManagedAddress,
     );
 
+    #[event("spending_paid")]
+    fn spending_paid_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] category: &ManagedBuffer,
+    );
+
+    #[event("budget_exceeded")]
+    fn budget_exceeded_event(
+        &self,
+        #[indexed] category: &ManagedBuffer,
+        #[indexed] budget: &BigUint,
+        #[indexed] spent: &BigUint,
+    );
+
     #[event("batch_transaction_event")]
     fn batch_transaction_event(
         &self,
@@ -320,6 +681,10 @@ pub trait PhilanthrifyCharity {
     #[storage_mapper("owner")]
     fn owner(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getPendingOwner)]
+    #[storage_mapper("pending_owner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
     #[view(getFactoryAddress)]
     #[storage_mapper("factory_address")]
     fn factory_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -327,4 +692,64 @@ pub trait PhilanthrifyCharity {
     #[view(getProjectTemplate)]
     #[storage_mapper("project_template")]
     fn project_template(&self) -> SingleValueMapper<ManagedAddress>;
-}
\ No newline at end of file
+
+    // Read directly from the factory (same shard) to check name availability
+    #[storage_mapper_from_address("entity_id_by_name")]
+    fn factory_entity_id_by_name(
+        &self,
+        factory_address: ManagedAddress,
+        name: &ManagedBuffer,
+    ) -> SingleValueMapper<u64, ManagedAddress>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper_from_address("paused")]
+    fn factory_paused(&self, factory_address: ManagedAddress) -> SingleValueMapper<bool, ManagedAddress>;
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getAcceptedTokens)]
+    #[storage_mapper("accepted_tokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[storage_mapper("received_tokens")]
+    fn received_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getTotalReceived)]
+    #[storage_mapper("total_received")]
+    fn total_received(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalWithdrawn)]
+    #[storage_mapper("total_withdrawn")]
+    fn total_withdrawn(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalSpent)]
+    #[storage_mapper("total_spent")]
+    fn total_spent(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(isDisplayOnlyDonationsEnabled)]
+    #[storage_mapper("display_only_donations_enabled")]
+    fn display_only_donations_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getSpendingCategories)]
+    #[storage_mapper("spending_categories")]
+    fn spending_categories(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("category_budget")]
+    fn category_budget(&self, category: &ManagedBuffer) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("category_budget_token")]
+    fn category_budget_token(&self, category: &ManagedBuffer) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getCategorySpent)]
+    #[storage_mapper("category_spent")]
+    fn category_spent(&self, category: &ManagedBuffer, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getBudgetPolicy)]
+    #[storage_mapper("budget_policy")]
+    fn budget_policy(&self) -> SingleValueMapper<BudgetPolicy>;
+}
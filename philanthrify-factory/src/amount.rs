@@ -0,0 +1,36 @@
+//! Decimal rendering of token amounts for NFT tags and traits.
+
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{BigUint, ManagedBuffer},
+};
+
+/// Renders `amount` (in the token's smallest unit) with `decimals` decimals, rounded half-up to
+/// at most `precision` fraction digits with trailing zeros trimmed, e.g. 12.5 EGLD as "12.5".
+pub fn format_amount<M: ManagedTypeApi>(amount: &BigUint<M>, decimals: u32, precision: u32) -> ManagedBuffer<M> {
+    let precision = precision.min(decimals);
+    let dropped_digits = decimals - precision;
+    let mut scaled = amount.clone();
+    if dropped_digits > 0 {
+        let divisor = BigUint::from(10u32).pow(dropped_digits);
+        let half = &divisor / 2u32;
+        scaled = (scaled + half) / divisor;
+    }
+
+    let unit = BigUint::from(10u32).pow(precision);
+    let mut formatted = (&scaled / &unit).to_display();
+    let fraction = scaled % unit;
+    if fraction == 0u32 {
+        return formatted;
+    }
+
+    let digits = fraction.to_display().to_boxed_bytes();
+    let digits = digits.as_slice();
+    let significant = digits.iter().rposition(|&digit| digit != b'0').map_or(0, |last| last + 1);
+    formatted.append_bytes(b".");
+    for _ in digits.len()..precision as usize {
+        formatted.append_bytes(b"0");
+    }
+    formatted.append_bytes(&digits[..significant]);
+    formatted
+}
@@ -0,0 +1,157 @@
+//! Builder for the `metadata:CID;tags:a,b;image:URI;traits:[...]` NFT attribute string.
+//! Tags are sanitized so user input cannot add tags or sections, trait values are JSON-escaped.
+
+use multiversx_sc::{api::ManagedTypeApi, types::ManagedBuffer};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+pub struct NftAttributes<M: ManagedTypeApi> {
+    metadata_cid: ManagedBuffer<M>,
+    tags: ManagedBuffer<M>,
+    image: ManagedBuffer<M>,
+    traits: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> NftAttributes<M> {
+    /// Empty CID leaves out the `metadata:` section.
+    pub fn new(metadata_cid: &ManagedBuffer<M>) -> Self {
+        NftAttributes {
+            metadata_cid: sanitize_segment(metadata_cid),
+            tags: ManagedBuffer::new(),
+            image: ManagedBuffer::new(),
+            traits: ManagedBuffer::new(),
+        }
+    }
+
+    /// Adds a tag, replacing `,` and `;` with `_`. Empty tags are skipped.
+    pub fn tag(&mut self, tag: &ManagedBuffer<M>) {
+        if tag.is_empty() {
+            return;
+        }
+        self.start_tag();
+        self.tags.append(&sanitize_tag(tag));
+    }
+
+    pub fn tag_bytes(&mut self, tag: &[u8]) {
+        self.tag(&ManagedBuffer::new_from_bytes(tag));
+    }
+
+    /// Adds a `prefix` + value tag such as `tier:Gold` or `donated$12`; only the value is sanitized.
+    pub fn tag_with_value(&mut self, prefix: &[u8], value: &ManagedBuffer<M>) {
+        self.start_tag();
+        self.tags.append_bytes(prefix);
+        self.tags.append(&sanitize_tag(value));
+    }
+
+    /// Empty URI leaves out the `image:` section.
+    pub fn image(&mut self, uri: &ManagedBuffer<M>) {
+        self.image = sanitize_segment(uri);
+    }
+
+    pub fn add_trait(&mut self, trait_type: &[u8], value: &ManagedBuffer<M>) {
+        if !self.traits.is_empty() {
+            self.traits.append_bytes(b",");
+        }
+        self.traits.append_bytes(b"{\"trait_type\":\"");
+        self.traits.append(&escape_json(&ManagedBuffer::new_from_bytes(trait_type)));
+        self.traits.append_bytes(b"\",\"value\":\"");
+        self.traits.append(&escape_json(value));
+        self.traits.append_bytes(b"\"}");
+    }
+
+    pub fn add_trait_bytes(&mut self, trait_type: &[u8], value: &[u8]) {
+        self.add_trait(trait_type, &ManagedBuffer::new_from_bytes(value));
+    }
+
+    /// Traits alone as a JSON array.
+    pub fn traits_json(&self) -> ManagedBuffer<M> {
+        let mut json = ManagedBuffer::new_from_bytes(b"[");
+        json.append(&self.traits);
+        json.append_bytes(b"]");
+        json
+    }
+
+    /// Full attribute string; the traits section is only written when traits were added.
+    pub fn build(&self) -> ManagedBuffer<M> {
+        let mut attributes = ManagedBuffer::new();
+        if !self.metadata_cid.is_empty() {
+            attributes.append_bytes(b"metadata:");
+            attributes.append(&self.metadata_cid);
+            attributes.append_bytes(b";");
+        }
+        attributes.append_bytes(b"tags:");
+        attributes.append(&self.tags);
+        if !self.image.is_empty() {
+            attributes.append_bytes(b";image:");
+            attributes.append(&self.image);
+        }
+        if !self.traits.is_empty() {
+            attributes.append_bytes(b";traits:");
+            attributes.append(&self.traits_json());
+        }
+        attributes
+    }
+
+    fn start_tag(&mut self) {
+        if !self.tags.is_empty() {
+            self.tags.append_bytes(b",");
+        }
+    }
+}
+
+/// Replaces the tag and section separators `,` and `;` with `_`.
+pub fn sanitize_tag<M: ManagedTypeApi>(tag: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    replace_bytes(tag, |byte| matches!(byte, b',' | b';'))
+}
+
+/// Replaces the section separator `;` with `_`, for CIDs and URIs.
+pub fn sanitize_segment<M: ManagedTypeApi>(segment: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    replace_bytes(segment, |byte| byte == b';')
+}
+
+/// Escapes `"`, `\` and control characters for use inside a JSON string, and `;` as `\u003b` so a
+/// value cannot end the traits section.
+pub fn escape_json<M: ManagedTypeApi>(value: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    let bytes = value.to_boxed_bytes();
+    let bytes = bytes.as_slice();
+    let mut escaped = ManagedBuffer::new();
+    let mut run_start = 0;
+    for (index, &byte) in bytes.iter().enumerate() {
+        if byte != b'"' && byte != b'\\' && byte != b';' && byte >= 0x20 {
+            continue;
+        }
+        escaped.append_bytes(&bytes[run_start..index]);
+        match byte {
+            b'"' => escaped.append_bytes(b"\\\""),
+            b'\\' => escaped.append_bytes(b"\\\\"),
+            b'\n' => escaped.append_bytes(b"\\n"),
+            b'\r' => escaped.append_bytes(b"\\r"),
+            b'\t' => escaped.append_bytes(b"\\t"),
+            _ => escaped.append_bytes(&[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                HEX_DIGITS[(byte >> 4) as usize],
+                HEX_DIGITS[(byte & 0x0f) as usize],
+            ]),
+        }
+        run_start = index + 1;
+    }
+    escaped.append_bytes(&bytes[run_start..]);
+    escaped
+}
+
+fn replace_bytes<M: ManagedTypeApi>(value: &ManagedBuffer<M>, is_reserved: impl Fn(u8) -> bool) -> ManagedBuffer<M> {
+    let bytes = value.to_boxed_bytes();
+    if !bytes.as_slice().iter().any(|&byte| is_reserved(byte)) {
+        return value.clone();
+    }
+    let mut replaced = bytes.into_vec();
+    for byte in replaced.iter_mut() {
+        if is_reserved(*byte) {
+            *byte = b'_';
+        }
+    }
+    ManagedBuffer::new_from_bytes(&replaced)
+}
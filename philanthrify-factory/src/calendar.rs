@@ -0,0 +1,40 @@
+//! Gregorian calendar helpers for block timestamps (seconds since 1970-01-01 UTC).
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Converts a timestamp to its (year, month 1-12, day 1-31) civil date.
+/// Howard Hinnant's days-to-civil algorithm, restricted to dates on or after 1970-01-01.
+pub fn civil_from_timestamp(timestamp: u64) -> (u64, u64, u64) {
+    let z = timestamp / SECONDS_PER_DAY + 719_468;  // Days since 0000-03-01
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;  // 0 = March ... 11 = February
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Month in YYYYMM format, e.g. 202601 for January 2026.
+pub fn month_of(timestamp: u64) -> u64 {
+    let (year, month, _) = civil_from_timestamp(timestamp);
+    year * 100 + month
+}
+
+/// Quarter in YYYYQ format, e.g. 20261 for Q1 2026.
+pub fn quarter_of(timestamp: u64) -> u64 {
+    let (year, month, _) = civil_from_timestamp(timestamp);
+    year * 10 + (month - 1) / 3 + 1
+}
+
+/// Absolute month count of a YYYYMM value, so consecutive months differ by exactly 1 across years.
+pub fn month_index(yyyymm: u64) -> u64 {
+    (yyyymm / 100) * 12 + (yyyymm % 100).saturating_sub(1)
+}
+
+/// Absolute quarter count of a YYYYQ value, so consecutive quarters differ by exactly 1 across years.
+pub fn quarter_index(yyyyq: u64) -> u64 {
+    (yyyyq / 10) * 4 + (yyyyq % 10).saturating_sub(1)
+}
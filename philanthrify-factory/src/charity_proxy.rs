@@ -0,0 +1,685 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct PhilanthrifyCharityProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for PhilanthrifyCharityProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = PhilanthrifyCharityProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        PhilanthrifyCharityProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct PhilanthrifyCharityProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> PhilanthrifyCharityProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        charity_name: Arg0,
+        factory_address: Arg1,
+        owner_address: Arg2,
+        project_template: Arg3,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&charity_name)
+            .argument(&factory_address)
+            .argument(&owner_address)
+            .argument(&project_template)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PhilanthrifyCharityProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PhilanthrifyCharityProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn has_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn transaction_for_charity<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        display_amount: Arg0,
+        category: Arg1,
+        description: Arg2,
+        user_image_uri: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transactionForCharity")
+            .argument(&display_amount)
+            .argument(&category)
+            .argument(&description)
+            .argument(&user_image_uri)
+            .original_result()
+    }
+
+    /// Pays a supplier or beneficiary from the treasury and mints the spending receipt, 
+    /// so the spending ledger matches money that actually left the charity. 
+    pub fn paid_transaction_for_charity<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+        amount: Arg1,
+        recipient: Arg2,
+        category: Arg3,
+        description: Arg4,
+        user_image_uri: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("paidTransactionForCharity")
+            .argument(&token_identifier)
+            .argument(&amount)
+            .argument(&recipient)
+            .argument(&category)
+            .argument(&description)
+            .argument(&user_image_uri)
+            .original_result()
+    }
+
+    pub fn batch_transaction_for_charity<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, BigUint<Env::Api>>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        num_transactions: Arg0,
+        display_amounts: Arg1,
+        categories: Arg2,
+        descriptions: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("batchTransactionForCharity")
+            .argument(&num_transactions)
+            .argument(&display_amounts)
+            .argument(&categories)
+            .argument(&descriptions)
+            .original_result()
+    }
+
+    /// Adds a category to the catalogue or changes its budget (0 = no cap). The budget only caps 
+    /// spending in `token_identifier` (display-only spending counts as EGLD); other tokens are tracked uncapped. 
+    /// Once the catalogue has entries, spending must use one of them. 
+    pub fn set_spending_category<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        category: Arg0,
+        token_identifier: Arg1,
+        budget: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSpendingCategory")
+            .argument(&category)
+            .argument(&token_identifier)
+            .argument(&budget)
+            .original_result()
+    }
+
+    /// Running totals are kept in case the category is added back. 
+    pub fn remove_spending_category<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        category: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeSpendingCategory")
+            .argument(&category)
+            .original_result()
+    }
+
+    pub fn set_budget_policy<
+        Arg0: ProxyArg<BudgetPolicy>,
+    >(
+        self,
+        policy: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBudgetPolicy")
+            .argument(&policy)
+            .original_result()
+    }
+
+    /// Budget vs spent, in the budget's token, for every catalogue category. 
+    pub fn get_category_budgets(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, CategoryBudget<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCategoryBudgets")
+            .original_result()
+    }
+
+    pub fn donate_to_charity<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        user_image_uri: Arg0,
+        custom_tags: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("donateToCharity")
+            .argument(&user_image_uri)
+            .argument(&custom_tags)
+            .original_result()
+    }
+
+    /// Off-chain (fiat) donations recorded for `donor`: no value moves, display_amount is for NFT display only. 
+    /// Disabled unless the owner opts in via setDisplayOnlyDonations; only the owner or a processor may record them. 
+    pub fn donate_display_only<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        donor: Arg0,
+        display_amount: Arg1,
+        user_image_uri: Arg2,
+        custom_tags: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("donateDisplayOnly")
+            .argument(&donor)
+            .argument(&display_amount)
+            .argument(&user_image_uri)
+            .argument(&custom_tags)
+            .original_result()
+    }
+
+    /// Settles off-chain (fiat) donations for many donors in one factory call; amounts are for NFT display only. 
+    pub fn batch_donate_to_charity<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, DonationItem<Env::Api>>>,
+    >(
+        self,
+        donations: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("batchDonateToCharity")
+            .argument(&donations)
+            .original_result()
+    }
+
+    pub fn add_accepted_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAcceptedToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn remove_accepted_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAcceptedToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn set_display_only_donations<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDisplayOnlyDonations")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn withdraw<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        token_identifier: Arg0,
+        amount: Arg1,
+        opt_to: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw")
+            .argument(&token_identifier)
+            .argument(&amount)
+            .argument(&opt_to)
+            .original_result()
+    }
+
+    /// Received minus withdrawn minus paid out as spending. 
+    pub fn get_available_balance<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAvailableBalance")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    /// Per-token accounting for every token ever donated: (token, received, withdrawn, available). 
+    /// Available is also net of spending; see getTotalSpent. 
+    pub fn get_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue4<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasury")
+            .original_result()
+    }
+
+    pub fn deploy_project<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        project_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("deployProject")
+            .argument(&project_name)
+            .original_result()
+    }
+
+    pub fn set_project_template<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        project_template: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProjectTemplate")
+            .argument(&project_template)
+            .original_result()
+    }
+
+    pub fn transfer_ownership<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferOwnership")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    pub fn accept_ownership(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptOwnership")
+            .original_result()
+    }
+
+    pub fn cancel_ownership_transfer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelOwnershipTransfer")
+            .original_result()
+    }
+
+    pub fn charity_name(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCharityName")
+            .original_result()
+    }
+
+    pub fn owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwner")
+            .original_result()
+    }
+
+    pub fn pending_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingOwner")
+            .original_result()
+    }
+
+    pub fn factory_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFactoryAddress")
+            .original_result()
+    }
+
+    pub fn project_template(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProjectTemplate")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn role_members<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleMembers")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn accepted_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAcceptedTokens")
+            .original_result()
+    }
+
+    pub fn total_received<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalReceived")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn total_withdrawn<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalWithdrawn")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn total_spent<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalSpent")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn display_only_donations_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDisplayOnlyDonationsEnabled")
+            .original_result()
+    }
+
+    pub fn spending_categories(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingCategories")
+            .original_result()
+    }
+
+    pub fn category_spent<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        category: Arg0,
+        token_identifier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCategorySpent")
+            .argument(&category)
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn budget_policy(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BudgetPolicy> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBudgetPolicy")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Treasurer,
+    Editor,
+    Processor,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    Warn,
+    Reject,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct CategoryBudget<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub category: ManagedBuffer<Api>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub budget: BigUint<Api>,
+    pub spent: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct DonationItem<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub donor: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+    pub user_image_uri: ManagedBuffer<Api>,
+    pub tags: ManagedVec<Api, ManagedBuffer<Api>>,
+}
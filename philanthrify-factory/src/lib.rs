@@ -5,11 +5,29 @@ extern crate alloc;
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod amount;
+pub mod attributes;
+pub mod calendar;
+pub mod charity_proxy;
+
+use attributes::NftAttributes;
+
+const BPS: u64 = 10_000;
+const DEFAULT_LEADERBOARD_SIZE: usize = 10;
+const MAX_LEADERBOARD_SIZE: usize = 100;
+const MAX_BADGES_PER_RETRIEVAL: usize = 100;
+const MAX_TOKEN_DECIMALS: u32 = 18;
+const MAX_BATCH_ITEMS: usize = 100;
+const MAX_RANK_SCAN_DONORS: usize = 1_000;
+const MAX_BACKFILL_NONCES: u64 = 500;
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct DonationRecord<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
     pub amount: BigUint<M>,
     pub timestamp: u64,
+    pub entity_id: u64,
     pub entity_name: ManagedBuffer<M>,
     pub entity_type: ManagedBuffer<M>,
     pub nft_nonce: u64,
@@ -28,26 +46,172 @@ pub struct GlobalStats<M: ManagedTypeApi> {
 pub struct NftMetadataRecord<M: ManagedTypeApi> {
     pub nft_nonce: u64,
     pub donor_address: ManagedAddress<M>,
+    pub entity_id: u64,
     pub entity_name: ManagedBuffer<M>,
     pub entity_type: ManagedBuffer<M>,
     pub donation_count: u64,
     pub tier_level: u64,
-    pub total_amount: BigUint<M>,
+    pub total_amount: BigUint<M>,  // Badges: ranking total in EGLD terms; receipts: spent in last_token_identifier
+    pub last_token_identifier: EgldOrEsdtTokenIdentifier<M>,  // Token of the most recent donation/transaction
+    pub last_amount: BigUint<M>,
     pub last_updated: u64,
     pub is_on_contract: bool,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    PlatformAdmin,  // Everything the global admin can do except upgrades, ownership and admin grants
+    NftManager,     // NFT metadata, URIs and tier/patron images
+    Auditor,        // Flags spending ledger entries for review
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct EntityInfo<M: ManagedTypeApi> {
+    pub id: u64,  // Factory-assigned, never reused; all per-entity state is keyed by it
+    pub name: ManagedBuffer<M>,
+    pub entity_type: ManagedBuffer<M>,  // "charity" or "project"
+}
+
+/// One donor's donation in a mintNftBatch call.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct DonationItem<M: ManagedTypeApi> {
+    pub donor: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+    pub user_image_uri: ManagedBuffer<M>,  // Empty for no image
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// One spending record in a mintTransactionNftBatch call.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TransactionItem<M: ManagedTypeApi> {
+    pub display_amount: BigUint<M>,
+    pub category: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub user_image_uri: ManagedBuffer<M>,  // Empty for no image
+}
+
+/// An entity's tier table and policy, read from storage once per mint call.
+pub struct TierLadder<M: ManagedTypeApi> {
+    pub tiers: ManagedVec<M, TierConfig<M>>,
+    pub policy: TierPolicy,
+}
+
+/// State shared by every item of a mint call, loaded once.
+pub struct MintContext<M: ManagedTypeApi> {
+    pub entity: EntityInfo<M>,
+    pub nft_token_id: TokenIdentifier<M>,
+    pub ladder: TierLadder<M>,
+}
+
+/// Where a donation left the donor on the leaderboard.
+pub struct PatronPlacement<M: ManagedTypeApi> {
+    pub rank: Option<u64>,
+    /// Donors now ranked rank+1..=shifted_to each moved one place down (empty range when nobody moved)
+    pub shifted_to: u64,
+    pub evicted: Option<ManagedAddress<M>>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DirectoryEntry<M: ManagedTypeApi> {
+    pub id: u64,
+    pub address: ManagedAddress<M>,
+    pub name: ManagedBuffer<M>,
+    pub entity_type: ManagedBuffer<M>,
+    pub parent: ManagedAddress<M>,  // Parent charity for projects, zero address for charities
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TierConfig<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,       // Display name, e.g. "Gold"
+    pub tag: ManagedBuffer<M>,        // Lowercase explorer tag, e.g. "gold"
+    pub image_uri: ManagedBuffer<M>,  // Tier badge image, shown via ;image: and added as URI on tier change
+    pub min_donations: u64,           // Donations to the entity needed (DonationCount / Weighted policies)
+    pub min_amount: BigUint<M>,       // Total donated to the entity needed (TotalAmount / Weighted policies)
+}
+
+/// How a donor's tier is derived from their donations to an entity.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum TierPolicy {
+    DonationCount,
+    TotalAmount,
+    /// Weighted progress towards both thresholds; count_weight_bps of 10_000 is count only, 0 is amount only
+    Weighted { count_weight_bps: u64 },
+}
+
+/// How raw amounts are rendered in NFT tags and traits.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub struct AmountFormat {
+    pub decimals: u32,   // Decimals of totals and receipt amounts (EGLD: 18)
+    pub precision: u32,  // Fraction digits shown, rounded half-up
+}
+
+/// How an entity's spending transactions are receipted.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptMode {
+    /// One receipt per entity carrying the running totals (default; first so it matches empty storage)
+    Aggregated,
+    /// A separate receipt per transaction with its amount, category and description
+    PerTransaction,
+}
+
+/// One spending transaction in an entity's on-chain ledger.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct SpendingRecord<M: ManagedTypeApi> {
+    pub id: u64,  // 1-based position in the entity's ledger
+    pub entity_id: u64,
+    pub amount: BigUint<M>,
+    pub category: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub timestamp: u64,
+    pub receipt_nonce: u64,  // Per-transaction receipt, or the entity's aggregated one
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub recipient: ManagedAddress<M>,  // Who was paid from the treasury; zero for display-only records
+}
+
+/// An auditor's note on a spending record, shown next to the ledger until cleared.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct SpendingFlag<M: ManagedTypeApi> {
+    pub record_id: u64,
+    pub auditor: ManagedAddress<M>,
+    pub reason: ManagedBuffer<M>,
+    pub timestamp: u64,
+}
+
+/// Whether a donor badge may leave the factory once minted.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeCustody {
+    /// Badge can be retrieved, traded and returned (default; first so it matches empty storage)
+    Transferable,
+    /// Badge can be retrieved and the factory then asks the system contract to freeze it in the donor's wallet.
+    /// The freeze is asynchronous and not guaranteed; isBadgeFrozen reports whether it took effect
+    RetrievableFrozen,
+    /// Badge never leaves the factory; donors see it through getDonorNftMetadataForEntity
+    Soulbound,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct PatronRecord<M: ManagedTypeApi> {
     pub donor_address: ManagedAddress<M>,
     pub total_amount: BigUint<M>,
-    pub patron_rank: u64,  // 1-10
+    pub patron_rank: u64,  // 1-based position on the entity leaderboard
     pub since_timestamp: u64,
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
 pub struct RecurringPattern {
     pub monthly_streak: u64,
     pub quarterly_streak: u64,
@@ -55,17 +219,6 @@ pub struct RecurringPattern {
     pub last_donation_quarter: u64,  // Format: YYYYQ (e.g., 20261 for Q1)
 }
 
-impl RecurringPattern {
-    pub fn default() -> Self {
-        RecurringPattern {
-            monthly_streak: 0,
-            quarterly_streak: 0,
-            last_donation_month: 0,
-            last_donation_quarter: 0,
-        }
-    }
-}
-
 #[multiversx_sc::contract]
 pub trait PhilanthrifyFactory {
     #[init]
@@ -81,11 +234,15 @@ pub trait PhilanthrifyFactory {
             total_nfts_minted: 0u64,
         };
         self.global_statistics().set(&stats);
+        self.seed_default_tiers();
     }
 
     #[upgrade]
     fn upgrade(&self) {
         self.only_owner();
+        if self.tiers().is_empty() {
+            self.seed_default_tiers();
+        }
     }
 
     fn only_owner(&self) {
@@ -94,20 +251,148 @@ pub trait PhilanthrifyFactory {
         require!(caller == owner, "Only global admin allowed");
     }
 
+    fn only_platform_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.global_admin_address().get() || self.has_role(Role::PlatformAdmin, &caller),
+            "Only global admin or platform admin allowed"
+        );
+    }
+
+    fn only_nft_manager(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.global_admin_address().get()
+                || self.has_role(Role::PlatformAdmin, &caller)
+                || self.has_role(Role::NftManager, &caller),
+            "Only global admin, platform admin or NFT manager allowed"
+        );
+    }
+
+    fn only_auditor(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.global_admin_address().get()
+                || self.has_role(Role::PlatformAdmin, &caller)
+                || self.has_role(Role::Auditor, &caller),
+            "Only global admin, platform admin or auditor allowed"
+        );
+    }
+
+    // ============================================================
+    // ROLES
+    // ============================================================
+
+    /// Platform admins can grant every role except PlatformAdmin, which only the global admin grants.
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_can_manage_role(role);
+        require!(!address.is_zero(), "Invalid address");
+        require!(self.role_members(role).insert(address.clone()), "Address already has role");
+        self.role_granted(role, &address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_can_manage_role(role);
+        require!(self.role_members(role).swap_remove(&address), "Address does not have role");
+        self.role_revoked(role, &address);
+    }
+
+    fn require_can_manage_role(&self, role: Role) {
+        if role == Role::PlatformAdmin {
+            self.only_owner();
+        } else {
+            self.only_platform_admin();
+        }
+    }
+
+    fn has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        self.role_members(role).contains(address)
+    }
+
+    #[view(hasRole)]
+    fn has_role_view(&self, role: Role, address: ManagedAddress) -> bool {
+        self.has_role(role, &address)
+    }
+
+    // ============================================================
+    // PAUSE - Global circuit breaker, also read by every charity/project
+    // ============================================================
+
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.only_platform_admin();
+        require!(!self.paused().get(), "Already paused");
+        self.paused().set(true);
+        self.paused_event(&self.blockchain().get_caller());
+    }
+
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.only_platform_admin();
+        require!(self.paused().get(), "Not paused");
+        self.paused().set(false);
+        self.unpaused_event(&self.blockchain().get_caller());
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Platform is paused");
+    }
+
+    // ============================================================
+    // GLOBAL ADMIN TRANSFER - Propose, then the new admin accepts
+    // ============================================================
+
+    #[endpoint(transferGlobalAdmin)]
+    fn transfer_global_admin(&self, new_admin: ManagedAddress) {
+        self.only_owner();
+        require!(!new_admin.is_zero(), "Invalid admin address");
+        let current_admin = self.global_admin_address().get();
+        require!(new_admin != current_admin, "Address is already global admin");
+
+        self.pending_global_admin().set(&new_admin);
+        self.ownership_transfer_started(&current_admin, &new_admin);
+    }
+
+    #[endpoint(acceptGlobalAdmin)]
+    fn accept_global_admin(&self) {
+        let pending_mapper = self.pending_global_admin();
+        require!(!pending_mapper.is_empty(), "No pending global admin");
+        let new_admin = pending_mapper.take();
+        require!(self.blockchain().get_caller() == new_admin, "Only pending global admin allowed");
+
+        let previous_admin = self.global_admin_address().get();
+        self.global_admin_address().set(&new_admin);
+        self.ownership_transferred(&previous_admin, &new_admin);
+    }
+
+    #[endpoint(cancelGlobalAdminTransfer)]
+    fn cancel_global_admin_transfer(&self) {
+        self.only_owner();
+        let pending_mapper = self.pending_global_admin();
+        require!(!pending_mapper.is_empty(), "No pending global admin");
+        let pending_admin = pending_mapper.take();
+        self.ownership_transfer_cancelled(&pending_admin);
+    }
+
     #[endpoint(deployCharity)]
     fn deploy_charity(&self, charity_name: ManagedBuffer) -> ManagedAddress {
-        self.only_owner();
+        self.only_platform_admin();
+        self.require_not_paused();
 
         let charity_template = self.charity_template().get();
         require!(!charity_template.is_zero(), "Charity template not set");
+        self.require_entity_name_available(&charity_name);
 
         let admin_address = self.global_admin_address().get();
         let factory_address = self.blockchain().get_sc_address();
         let project_template = self.project_template().get();
 
-        let new_charity: ManagedAddress = self
+        let new_charity = self
             .tx()
-            .raw_deploy()
+            .typed(charity_proxy::PhilanthrifyCharityProxy)
+            .init(&charity_name, &factory_address, &admin_address, &project_template)
             .from_source(charity_template)
             .code_metadata(
                 CodeMetadata::PAYABLE
@@ -115,32 +400,157 @@ pub trait PhilanthrifyFactory {
                     | CodeMetadata::UPGRADEABLE
                     | CodeMetadata::READABLE,
             )
-            .argument(&charity_name)
-            .argument(&factory_address)
-            .argument(&admin_address)
-            .argument(&project_template)
             .gas(80_000_000)
-            .returns(ReturnsNewAddress)
-            .sync_call()
-            .into();
+            .returns(ReturnsNewManagedAddress)
+            .sync_call();
 
-        let current_count = self.charity_count().get();
-        self.charity_count().set(current_count + 1);
+        // Only registered entities may mint, so the new charity is authorized right away
+        self.register_entity_internal(&new_charity, &charity_name, &ManagedBuffer::from(b"charity"), None);
 
         self.charity_deployed(&charity_name, &new_charity);
         new_charity
     }
 
+    // ============================================================
+    // ENTITY REGISTRY - Only factory-deployed charities/projects can mint
+    // ============================================================
+
+    /// Called by a registered charity right after it deployed a project, so the project can mint.
+    #[endpoint(registerProject)]
+    fn register_project(&self, project_address: ManagedAddress, project_name: ManagedBuffer) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let charity_mapper = self.registered_entity(&caller);
+        require!(
+            !charity_mapper.is_empty() && charity_mapper.get().entity_type == b"charity",
+            "Only registered charities can register projects"
+        );
+        require!(!project_address.is_zero(), "Invalid project address");
+
+        self.register_entity_internal(&project_address, &project_name, &ManagedBuffer::from(b"project"), Some(&caller));
+        self.project_deployed(&project_name, &project_address, &caller);
+    }
+
+    /// Admin escape hatch for entities deployed before the registry existed.
+    /// Projects must name their (already registered) parent charity.
+    #[endpoint(registerEntity)]
+    fn register_entity(
+        &self,
+        address: ManagedAddress,
+        name: ManagedBuffer,
+        entity_type: ManagedBuffer,
+        opt_parent_charity: OptionalValue<ManagedAddress>,
+    ) {
+        self.only_platform_admin();
+        require!(!address.is_zero(), "Invalid entity address");
+
+        if entity_type == b"charity" {
+            require!(opt_parent_charity.is_none(), "Charities have no parent");
+            self.register_entity_internal(&address, &name, &entity_type, None);
+        } else if entity_type == b"project" {
+            let parent = opt_parent_charity
+                .into_option()
+                .unwrap_or_else(|| sc_panic!("Projects require a parent charity"));
+            let parent_mapper = self.registered_entity(&parent);
+            require!(
+                !parent_mapper.is_empty() && parent_mapper.get().entity_type == b"charity",
+                "Parent must be a registered charity"
+            );
+            self.register_entity_internal(&address, &name, &entity_type, Some(&parent));
+        } else {
+            sc_panic!("Entity type must be charity or project");
+        }
+    }
+
+    #[endpoint(unregisterEntity)]
+    fn unregister_entity(&self, address: ManagedAddress) {
+        self.only_platform_admin();
+        let entity_mapper = self.registered_entity(&address);
+        require!(!entity_mapper.is_empty(), "Entity not registered");
+        let entity = entity_mapper.get();
+        if entity.entity_type == b"charity" {
+            // Projects would be left pointing at a charity that is no longer listed
+            require!(self.charity_projects(&address).is_empty(), "Unregister the charity's projects first");
+        }
+
+        // Remove from the directory too; counters keep counting everything ever registered
+        // The id is never reused, but the name becomes available again
+        self.entity_id_by_name(&entity.name).clear();
+        self.entity_address_by_id(entity.id).clear();
+        if entity.entity_type == b"charity" {
+            self.charity_addresses().swap_remove(&address);
+        } else {
+            let parent = self.project_parent(&address).get();
+            self.charity_projects(&parent).swap_remove(&address);
+            self.project_parent(&address).clear();
+        }
+
+        entity_mapper.clear();
+        self.entity_unregistered(&address);
+    }
+
+    fn register_entity_internal(
+        &self,
+        address: &ManagedAddress,
+        name: &ManagedBuffer,
+        entity_type: &ManagedBuffer,
+        parent_charity: Option<&ManagedAddress>,
+    ) {
+        require!(!name.is_empty(), "Entity name cannot be empty");
+        require!(self.registered_entity(address).is_empty(), "Entity already registered");
+        self.require_entity_name_available(name);
+
+        let entity_id = self.last_entity_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let entity = EntityInfo {
+            id: entity_id,
+            name: name.clone(),
+            entity_type: entity_type.clone(),
+        };
+        self.registered_entity(address).set(&entity);
+        self.entity_id_by_name(name).set(entity_id);
+        self.entity_address_by_id(entity_id).set(address);
+
+        // Directory: name <-> address, charity -> projects, project -> parent
+        match parent_charity {
+            None => {
+                self.charity_addresses().insert(address.clone());
+                self.charity_count().update(|count| *count += 1);
+            },
+            Some(parent) => {
+                self.charity_projects(parent).insert(address.clone());
+                self.project_parent(address).set(parent);
+                self.project_count().update(|count| *count += 1);
+            },
+        }
+
+        self.entity_registered(address, name, entity_type);
+    }
+
+    fn require_entity_name_available(&self, name: &ManagedBuffer) {
+        require!(self.entity_id_by_name(name).is_empty(), "Entity name already taken");
+    }
+
+    /// Mint calls must come from a registered entity; name/type are read from the registry, never from arguments.
+    fn require_registered_caller(&self) -> EntityInfo<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        let entity_mapper = self.registered_entity(&caller);
+        require!(!entity_mapper.is_empty(), "Caller is not a registered entity");
+        entity_mapper.get()
+    }
+
     #[endpoint(setCharityTemplate)]
     fn set_charity_template(&self, template_address: ManagedAddress) {
-        self.only_owner();
+        self.only_platform_admin();
         require!(!template_address.is_zero(), "Invalid template address");
         self.charity_template().set(&template_address);
     }
 
     #[endpoint(setProjectTemplate)]
     fn set_project_template(&self, template_address: ManagedAddress) {
-        self.only_owner();
+        self.only_platform_admin();
         require!(!template_address.is_zero(), "Invalid template address");
         self.project_template().set(&template_address);
     }
@@ -148,7 +558,7 @@ pub trait PhilanthrifyFactory {
     #[payable("EGLD")]
     #[endpoint(issuePhilanthrifyNft)]
     fn issue_philanthrify_nft(&self) {
-        self.only_owner();
+        self.only_platform_admin();
 
         let issue_cost = BigUint::from(50_000_000_000_000_000u64);
         let payment = self.call_value().egld();
@@ -168,8 +578,8 @@ pub trait PhilanthrifyFactory {
             .esdt_system_sc_proxy()
             .issue_non_fungible(
                 issue_cost,
-                &ManagedBuffer::from("PhilanthrifyDonor"),
-                &ManagedBuffer::from("PHILXY"),
+                ManagedBuffer::from("PhilanthrifyDonor"),
+                ManagedBuffer::from("PHILXY"),
                 NonFungibleTokenProperties {
                     can_freeze: true,
                     can_wipe: true,
@@ -198,13 +608,13 @@ pub trait PhilanthrifyFactory {
 
     #[endpoint(setGlobalNftCollection)]
     fn set_global_nft_collection(&self, nft_token_id: TokenIdentifier) {
-        self.only_owner();
+        self.only_platform_admin();
         self.global_nft_collection().set(&nft_token_id);
     }
 
     #[endpoint(grantNftRoleToFactory)]
     fn grant_nft_role_to_factory(&self) {
-        self.only_owner();
+        self.only_platform_admin();
 
         let nft_collection = self.global_nft_collection().get();
         require!(nft_collection.is_valid_esdt_identifier(), "NFT collection not set");
@@ -245,7 +655,7 @@ pub trait PhilanthrifyFactory {
         nft_nonce: u64,
         uri: ManagedBuffer,
     ) {
-        self.only_owner();
+        self.only_nft_manager();
 
         require!(nft_token_id.is_valid_esdt_identifier(), "Invalid NFT token ID");
 
@@ -261,7 +671,7 @@ pub trait PhilanthrifyFactory {
         nft_nonce: u64,
         attributes: ManagedBuffer,
     ) {
-        self.only_owner();
+        self.only_nft_manager();
 
         require!(nft_token_id.is_valid_esdt_identifier(), "Invalid NFT token ID");
 
@@ -284,7 +694,7 @@ pub trait PhilanthrifyFactory {
 
     #[endpoint(setDonorNftMetadata)]
     fn set_donor_nft_metadata(&self, ipfs_cid: ManagedBuffer) {
-        self.only_owner();
+        self.only_nft_manager();
         require!(!ipfs_cid.is_empty(), "IPFS CID cannot be empty");
         self.donor_nft_ipfs_cid().set(&ipfs_cid);
     }
@@ -292,13 +702,13 @@ pub trait PhilanthrifyFactory {
     /// Clear donor metadata CID so Attributes/Tags come only from on-chain (tags:...;traits:[...]). Call this if you want no Pinata JSON.
     #[endpoint(clearDonorNftMetadata)]
     fn clear_donor_nft_metadata(&self) {
-        self.only_owner();
+        self.only_nft_manager();
         self.donor_nft_ipfs_cid().clear();
     }
 
     #[endpoint(setTransactionNftMetadata)]
     fn set_transaction_nft_metadata(&self, ipfs_cid: ManagedBuffer) {
-        self.only_owner();
+        self.only_nft_manager();
         require!(!ipfs_cid.is_empty(), "IPFS CID cannot be empty");
         self.transaction_nft_ipfs_cid().set(&ipfs_cid);
     }
@@ -307,7 +717,7 @@ pub trait PhilanthrifyFactory {
     /// (MultiversX cannot remove URIs). Current tier image is only in attributes ;image:.
     #[endpoint(setDefaultDonorImageUri)]
     fn set_default_donor_image_uri(&self, uri: ManagedBuffer) {
-        self.only_owner();
+        self.only_nft_manager();
         self.default_donor_image_uri().set(&uri);
     }
 
@@ -318,43 +728,132 @@ pub trait PhilanthrifyFactory {
     fn mint_nft(
         &self,
         donor_address: ManagedAddress,
-        display_amount: BigUint,  // Display amount only (for NFT name), not actual EGLD
-        entity_name: ManagedBuffer,
-        entity_type: ManagedBuffer,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        is_display_only: bool,  // true for off-chain (fiat) donations: amount is for NFT display only, no EGLD moved
         user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
         custom_tags: MultiValueEncoded<ManagedBuffer>,  // Must be last (var-args)
     ) {
+        self.require_not_paused();
+        let entity = self.require_registered_caller();
+        let ctx = self.load_mint_context(entity);
+        let placement = self.mint_donation_nft(&ctx, donor_address, token_identifier, amount, is_display_only, user_image_uri, custom_tags.to_vec());
+
+        // Update the badges of patrons the donor overtook (tags + patron image) so e.g. old #1 shows #patron_2
+        self.update_shifted_patrons_nfts(&ctx, &placement);
+        if let Some(evicted_donor) = placement.evicted {
+            self.refresh_donor_badge(&ctx, &evicted_donor, None);
+        }
+    }
+
+    /// Records many donations to the calling entity in one call, e.g. a payment processor settling
+    /// off-chain donors. Config is loaded once and other patrons' badges are refreshed once at the end.
+    #[endpoint(mintNftBatch)]
+    fn mint_nft_batch(
+        &self,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        is_display_only: bool,
+        items: MultiValueEncoded<DonationItem<Self::Api>>,
+    ) {
+        self.require_not_paused();
+        let entity = self.require_registered_caller();
+        let item_count = items.len();
+        require!(item_count > 0 && item_count <= MAX_BATCH_ITEMS, "Batch must be 1-100");
+
+        let ctx = self.load_mint_context(entity);
+        // Rank each touched donor's badge currently shows (0 = none), starting from the board before the batch
+        let mut shown_ranks: ManagedVec<PatronRecord<Self::Api>> = ManagedVec::new();
+        for patron in self.project_patrons(ctx.entity.id).iter() {
+            shown_ranks.push(patron);
+        }
+        let mut total_amount = BigUint::zero();
+        for item in items.into_iter() {
+            require!(item.amount > 0u32, "Donation amount must be > 0");
+            total_amount += &item.amount;
+            let donor = item.donor.clone();
+            let placement = self.mint_donation_nft(&ctx, item.donor, token_identifier.clone(), item.amount, is_display_only, item.user_image_uri, item.tags);
+            self.set_shown_rank(&mut shown_ranks, &donor, placement.rank.unwrap_or(0));
+        }
+
+        // Recompute the leaderboard badges once: only donors whose final rank differs from their badge
+        for record in shown_ranks.iter() {
+            let patron_rank = self.get_donor_patron_rank(&record.donor_address, ctx.entity.id);
+            if patron_rank.unwrap_or(0) != record.patron_rank {
+                self.refresh_donor_badge(&ctx, &record.donor_address, patron_rank);
+            }
+        }
+        self.donation_batch_recorded(&ctx.entity.name, item_count, &total_amount);
+    }
+
+    fn set_shown_rank(&self, shown_ranks: &mut ManagedVec<PatronRecord<Self::Api>>, donor: &ManagedAddress, patron_rank: u64) {
+        for i in 0..shown_ranks.len() {
+            let mut record = shown_ranks.get(i).clone();
+            if &record.donor_address == donor {
+                record.patron_rank = patron_rank;
+                let _ = shown_ranks.set(i, record);
+                return;
+            }
+        }
+        shown_ranks.push(PatronRecord { donor_address: donor.clone(), total_amount: BigUint::zero(), patron_rank, since_timestamp: 0 });
+    }
+
+    fn load_mint_context(&self, entity: EntityInfo<Self::Api>) -> MintContext<Self::Api> {
         let nft_token_id = self.global_nft_collection().get();
         require!(nft_token_id.is_valid_esdt_identifier(), "NFT collection not set");
+        let ladder = self.load_tier_ladder(entity.id);
+        MintContext { entity, nft_token_id, ladder }
+    }
 
-        let current_donation_count = self.donor_donations_to_entity(&donor_address, &entity_name).get();
+    /// Records one donation and mints or updates the donor's badge.
+    /// Returns the donor's leaderboard placement; the caller refreshes the badges of shifted and evicted donors.
+    fn mint_donation_nft(
+        &self,
+        ctx: &MintContext<Self::Api>,
+        donor_address: ManagedAddress,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        is_display_only: bool,
+        user_image_uri: ManagedBuffer,
+        custom_tags: ManagedVec<ManagedBuffer>,
+    ) -> PatronPlacement<Self::Api> {
+        let entity_id = ctx.entity.id;
+        let entity_name = &ctx.entity.name;
+        let entity_type = &ctx.entity.entity_type;
+        let nft_token_id = &ctx.nft_token_id;
+
+        let current_donation_count = self.donor_donations_to_entity(&donor_address, entity_id).get();
         let new_donation_count = current_donation_count + 1;
 
-        // Track total amount per donor-entity
-        let current_total_amount = self.donor_total_amount_to_entity(&donor_address, &entity_name).get();
-        let new_total_amount = current_total_amount + &display_amount;
-        self.donor_total_amount_to_entity(&donor_address, &entity_name).set(&new_total_amount);
+        // Per-token totals are the record; the ranking total (tiers, leaderboard) is in EGLD terms
+        let current_total_amount = self.donor_total_amount_to_entity(&donor_address, entity_id).get();
+        let new_total_amount = current_total_amount + self.ranking_value(&token_identifier, &amount);
+        self.donor_total_amount_to_entity(&donor_address, entity_id).set(&new_total_amount);
+        self.donor_token_amount_to_entity(&donor_address, entity_id, &token_identifier)
+            .update(|total| *total += &amount);
+        self.donor_tokens_to_entity(&donor_address, entity_id).insert(token_identifier.clone());
 
-        let tier_level = self.calculate_tier_for_entity(new_donation_count);
-        let tier_name = self.get_tier_name(tier_level);
+        let tier_level = self.tier_level_in(&ctx.ladder, new_donation_count, &new_total_amount);
+        let tier = self.tier_in(&ctx.ladder, tier_level);
 
         // GAMIFICATION: Check patron status and update recurring patterns
-        let patron_rank = self.check_and_update_patrons(&donor_address, &entity_name, &new_total_amount);
-        let recurring_pattern = self.update_recurring_patterns(&donor_address, &entity_name);
+        let placement = self.check_and_update_patrons(&donor_address, entity_id, &new_total_amount);
+        let patron_rank = placement.rank;
+        let recurring_pattern = self.update_recurring_patterns(&donor_address, entity_id);
 
         let mut user_tags = ManagedVec::new();
-        for tag in custom_tags.into_iter() {
+        for tag in custom_tags.iter() {
             if !tag.is_empty() && user_tags.len() < 10 {
-                user_tags.push(tag);
+                user_tags.push(tag.clone());
             }
         }
 
         // Get registry - VecMapper uses 1-based indexing!
-        let mut registry = self.donor_nft_registry_for_entity(&donor_address, &entity_name);
+        let mut registry = self.donor_nft_registry_for_entity(&donor_address, entity_id);
         
         // Check if registry has any NFTs - VecMapper.len() returns the count
         // VecMapper indices are 1-based, so valid indices are 1 to len()
         let registry_len = registry.len();
+        let minted_new_nft = registry_len == 0;
         
         // Determine if we need to create new NFT or update existing one
         let target_nonce = if registry_len > 0 {
@@ -370,18 +869,19 @@ pub trait PhilanthrifyFactory {
                 metadata_mapper_pre.get().tier_level
             };
             
-            let tier_image_uri = self.get_tier_base_image_uri(tier_level);
+            let tier_image_uri = self.get_tier_image_uri(&tier);
             let tier_changed = old_tier != tier_level;
             
             // Create updated attributes with new donation count, gamification, and ;image: tier URI
             let updated_attributes = self.create_donation_nft_attributes(
-                &entity_name,
-                &entity_type,
+                entity_name,
+                entity_type,
                 new_donation_count,
-                tier_level,
-                &tier_name,
+                &tier,
                 &user_tags,
-                &new_total_amount,
+                &self.donor_token_payments(&donor_address, entity_id),
+                &token_identifier,
+                &amount,
                 patron_rank,
                 Some(&recurring_pattern),
                 tier_image_uri.clone(),
@@ -392,24 +892,24 @@ pub trait PhilanthrifyFactory {
             let contract_address = self.blockchain().get_sc_address();
             let contract_balance = self.blockchain().get_esdt_balance(
                 &contract_address,
-                &nft_token_id,
+                nft_token_id,
                 existing_nonce,
             );
             
             if contract_balance > 0u32 {
                 // NFT is on contract - update on-chain attributes so tags/traits/tier image upgrade
                 self.send().nft_update_attributes(
-                    &nft_token_id,
+                    nft_token_id,
                     existing_nonce,
                     &updated_attributes,
                 );
-                self.nft_attributes_updated(&donor_address, &entity_name, existing_nonce);
+                self.nft_attributes_updated(&donor_address, entity_name, existing_nonce);
 
                 // When tier changes, add the new tier image as a URI so the explorer can show it (many use last URI for display).
                 if tier_changed {
                     if let Some(ref uri) = tier_image_uri {
                         if !uri.is_empty() {
-                            self.send().nft_add_uri(&nft_token_id, existing_nonce, uri.clone());
+                            self.send().nft_add_uri(nft_token_id, existing_nonce, uri.clone());
                         }
                     }
                 }
@@ -423,7 +923,7 @@ pub trait PhilanthrifyFactory {
                         && (want_type == 1 || !legacy_has_image);  // always add top1 when now #1; for rest skip only if legacy
                     if should_add {
                         if let Some(patron_uri) = self.get_patron_badge_uri(rank) {
-                            self.send().nft_add_uri(&nft_token_id, existing_nonce, patron_uri);
+                            self.send().nft_add_uri(nft_token_id, existing_nonce, patron_uri);
                             self.patron_image_type_added(existing_nonce).set(want_type);
                             self.has_patron_uri(existing_nonce).set(true);
                         }
@@ -433,7 +933,7 @@ pub trait PhilanthrifyFactory {
                 // Add user image URI if provided (appends to history)
                 if !user_image_uri.is_empty() {
                     if let Some(formatted_uri) = self.format_user_image_uri(&user_image_uri) {
-                        self.send().nft_add_uri(&nft_token_id, existing_nonce, formatted_uri);
+                        self.send().nft_add_uri(nft_token_id, existing_nonce, formatted_uri);
                     }
                 }
             }
@@ -447,11 +947,14 @@ pub trait PhilanthrifyFactory {
                 NftMetadataRecord {
                     nft_nonce: existing_nonce,
                     donor_address: donor_address.clone(),
+                    entity_id,
                     entity_name: entity_name.clone(),
                     entity_type: entity_type.clone(),
                     donation_count: new_donation_count,
                     tier_level,
-                    total_amount: display_amount.clone(),
+                    total_amount: new_total_amount.clone(),
+                    last_token_identifier: token_identifier.clone(),
+                    last_amount: amount.clone(),
                     last_updated: self.blockchain().get_block_timestamp(),
                     is_on_contract,
                 }
@@ -461,14 +964,15 @@ pub trait PhilanthrifyFactory {
                 existing_metadata.donation_count = new_donation_count;
                 existing_metadata.tier_level = tier_level;
                 existing_metadata.total_amount = new_total_amount.clone();
+                existing_metadata.last_token_identifier = token_identifier.clone();
+                existing_metadata.last_amount = amount.clone();
                 existing_metadata.last_updated = self.blockchain().get_block_timestamp();
                 existing_metadata.is_on_contract = is_on_contract;
                 existing_metadata
             };
             metadata_mapper_pre.set(&metadata);
-
-            // When ranks change, update other patrons' NFTs (tags + patron image) so e.g. old #1 shows #patron_2
-            self.update_other_patrons_nfts(&donor_address, &entity_name, &entity_type, &nft_token_id);
+            // Backfills badges minted before donor_badges existed
+            self.donor_badges(&donor_address).insert(existing_nonce);
 
             existing_nonce
         } else {
@@ -481,17 +985,18 @@ pub trait PhilanthrifyFactory {
 
             // Tier image URI required for ;image: in attributes (display); we do NOT add it to URIs
             // so that after upgrade to Silver, Bronze IPFS is not in Assets (only current tier in attributes).
-            let tier_image_uri = self.get_tier_base_image_uri(tier_level)
+            let tier_image_uri = self.get_tier_image_uri(&tier)
                 .unwrap_or_else(|| sc_panic!("Tier image URI not configured. Run SET_IMAGE_URIS.sh first."));
 
             let attrs = self.create_donation_nft_attributes(
-                &entity_name,
-                &entity_type,
+                entity_name,
+                entity_type,
                 new_donation_count,
-                tier_level,
-                &tier_name,
+                &tier,
                 &user_tags,
-                &display_amount,
+                &self.donor_token_payments(&donor_address, entity_id),
+                &token_identifier,
+                &amount,
                 patron_rank,
                 Some(&recurring_pattern),
                 Some(tier_image_uri.clone()),
@@ -522,7 +1027,7 @@ pub trait PhilanthrifyFactory {
             }
 
             let created_nonce = self.send().esdt_nft_create(
-                &nft_token_id,
+                nft_token_id,
                 &BigUint::from(1u32),
                 &nft_name,
                 &royalties,
@@ -539,18 +1044,22 @@ pub trait PhilanthrifyFactory {
 
             // Add to registry
             registry.push(&created_nonce);
+            self.donor_badges(&donor_address).insert(created_nonce);
             self.nft_nonce().set(new_nonce);
-            self.nft_minted(&donor_address, &entity_name, created_nonce);
+            self.nft_minted(&donor_address, entity_name, created_nonce);
 
             // Store metadata record for new NFT
             let metadata = NftMetadataRecord {
                 nft_nonce: created_nonce,
                 donor_address: donor_address.clone(),
+                entity_id,
                 entity_name: entity_name.clone(),
                 entity_type: entity_type.clone(),
                 donation_count: new_donation_count,
                 tier_level,
-                total_amount: display_amount.clone(),
+                total_amount: new_total_amount.clone(),
+                last_token_identifier: token_identifier.clone(),
+                last_amount: amount.clone(),
                 last_updated: self.blockchain().get_block_timestamp(),
                 is_on_contract: true,
             };
@@ -562,23 +1071,26 @@ pub trait PhilanthrifyFactory {
         };
 
         let donation_record = DonationRecord {
-            amount: display_amount.clone(),  // Store display amount for records
+            token_identifier: token_identifier.clone(),
+            amount: amount.clone(),
             timestamp: self.blockchain().get_block_timestamp(),
+            entity_id,
             entity_name: entity_name.clone(),
             entity_type: entity_type.clone(),
             nft_nonce: target_nonce,
         };
 
         self.donor_donation_history(&donor_address).push(&donation_record);
-        self.entity_donation_history(&entity_name).push(&donation_record);
-        self.entity_type_donation_history(&entity_type).push(&donation_record);
+        self.entity_donation_history(entity_id).push(&donation_record);
+        self.entity_type_donation_history(entity_type).push(&donation_record);
 
-        self.donor_donations_to_entity(&donor_address, &entity_name).set(new_donation_count);
+        self.donor_donations_to_entity(&donor_address, entity_id).set(new_donation_count);
 
-        // Don't update stats with display amount (it's not real EGLD)
-        // self.update_donation_stats(&display_amount, minted_new_nft);
+        // Display-only amounts are not real EGLD, so they never count towards donation totals
+        self.update_donation_stats(&token_identifier, &amount, is_display_only, minted_new_nft);
 
-        self.donation_recorded(&donor_address, &display_amount, &entity_name);
+        self.donation_recorded(&donor_address, &amount, entity_name);
+        placement
     }
 
     // ============================================================
@@ -589,29 +1101,125 @@ pub trait PhilanthrifyFactory {
         &self,
         entity_owner: ManagedAddress,
         display_amount: BigUint,  // Display amount only (for NFT name), not actual EGLD
-        entity_name: ManagedBuffer,
-        entity_type: ManagedBuffer,
         category: ManagedBuffer,
         description: ManagedBuffer,
         user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
     ) {
+        self.require_not_paused();
+        let entity = self.require_registered_caller();
+        let mut items = ManagedVec::new();
+        items.push(TransactionItem { display_amount, category, description, user_image_uri });
+        let target_nonce = self.record_transactions(&entity, entity_owner, &items, &EgldOrEsdtTokenIdentifier::egld(), &ManagedAddress::zero());
+        self.transaction_nft_minted(&entity.name, &entity.entity_type, target_nonce);
+    }
+
+    /// Receipt for spending the entity actually paid out of its treasury to `recipient`.
+    #[endpoint(mintPaidTransactionNft)]
+    fn mint_paid_transaction_nft(
+        &self,
+        entity_owner: ManagedAddress,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        recipient: ManagedAddress,
+        category: ManagedBuffer,
+        description: ManagedBuffer,
+        user_image_uri: ManagedBuffer,
+    ) {
+        self.require_not_paused();
+        let entity = self.require_registered_caller();
+        require!(amount > 0u32, "Amount must be > 0");
+        require!(!recipient.is_zero(), "Invalid recipient address");
+        let mut items = ManagedVec::new();
+        items.push(TransactionItem { display_amount: amount, category, description, user_image_uri });
+        let target_nonce = self.record_transactions(&entity, entity_owner, &items, &token_identifier, &recipient);
+        self.transaction_nft_minted(&entity.name, &entity.entity_type, target_nonce);
+    }
+
+    /// Records many spending transactions of the calling entity in one call. The entity's receipt
+    /// is created or updated once with the combined totals; the last item's category and description show.
+    #[endpoint(mintTransactionNftBatch)]
+    fn mint_transaction_nft_batch(&self, entity_owner: ManagedAddress, items: MultiValueEncoded<TransactionItem<Self::Api>>) {
+        self.require_not_paused();
+        let entity = self.require_registered_caller();
+        let item_count = items.len();
+        require!(item_count > 0 && item_count <= MAX_BATCH_ITEMS, "Batch must be 1-100");
+
+        let items = items.to_vec();
+        let mut total_amount = BigUint::zero();
+        for item in items.iter() {
+            total_amount += &item.display_amount;
+        }
+        let target_nonce = self.record_transactions(&entity, entity_owner, &items, &EgldOrEsdtTokenIdentifier::egld(), &ManagedAddress::zero());
+        self.transaction_batch_recorded(&entity.name, target_nonce, item_count, &total_amount);
+    }
+
+    /// Adds the transactions to the entity's totals and spending ledger, then writes the receipts
+    /// the entity's receipt mode asks for. Returns the nonce of the last receipt written.
+    fn record_transactions(
+        &self,
+        entity: &EntityInfo<Self::Api>,
+        entity_owner: ManagedAddress,
+        items: &ManagedVec<TransactionItem<Self::Api>>,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        recipient: &ManagedAddress,
+    ) -> u64 {
+        let entity_id = entity.id;
+        let entity_name = &entity.name;
+        let entity_type = &entity.entity_type;
+
         let nft_token_id = self.global_nft_collection().get();
         require!(nft_token_id.is_valid_esdt_identifier(), "NFT collection not set");
 
-        // Track transaction statistics per entity
-        let current_total_amount = self.entity_transaction_total(&entity_name).get();
-        let new_total_amount = current_total_amount + &display_amount;
-        self.entity_transaction_total(&entity_name).set(&new_total_amount);
-
-        let current_transaction_count = self.entity_transaction_count(&entity_name).get();
-        let new_transaction_count = current_transaction_count + 1;
-        self.entity_transaction_count(&entity_name).set(new_transaction_count);
+        let mut batch_amount = BigUint::zero();
+        let mut user_image_uris: ManagedVec<ManagedBuffer> = ManagedVec::new();
+        for item in items.iter() {
+            batch_amount += &item.display_amount;
+            if !item.user_image_uri.is_empty() {
+                if let Some(formatted_uri) = self.format_user_image_uri(&item.user_image_uri) {
+                    user_image_uris.push(formatted_uri);
+                }
+            }
+        }
+        // The receipt shows the most recent transaction's details
+        let last_item = items.get(items.len() - 1).clone();
+        let display_amount = last_item.display_amount;
+        let category = last_item.category;
+        let description = last_item.description;
+
+        // Display-only spending (declared in EGLD, no recipient) is kept apart from what was paid out
+        // of the treasury, so the paid totals reconcile with the entity's own getTotalSpent
+        if recipient.is_zero() {
+            self.entity_transaction_total(entity_id).update(|total| *total += &batch_amount);
+        } else {
+            self.entity_spent_tokens(entity_id).insert(token_identifier.clone());
+            self.entity_spent_by_token(entity_id, token_identifier).update(|total| *total += &batch_amount);
+        }
+        let spent_totals = self.entity_spending_totals(entity_id);
+        let new_total_amount = spent_totals
+            .iter()
+            .find(|total| total.token_identifier == *token_identifier)
+            .map(|total| total.amount.clone())
+            .unwrap_or_default();
+
+        let current_transaction_count = self.entity_transaction_count(entity_id).get();
+        let new_transaction_count = current_transaction_count + items.len() as u64;
+        self.entity_transaction_count(entity_id).set(new_transaction_count);
+
+        if self.entity_receipt_mode(entity_id).get() == ReceiptMode::PerTransaction {
+            let mut receipt_nonce = 0;
+            for (index, item) in items.iter().enumerate() {
+                let transaction_number = current_transaction_count + index as u64 + 1;
+                receipt_nonce = self.mint_spending_receipt(entity, &entity_owner, &item, token_identifier, transaction_number);
+                self.record_spending(entity_id, &item, receipt_nonce, token_identifier, recipient);
+            }
+            return receipt_nonce;
+        }
 
         // Get or create transaction NFT for this entity (ONE NFT PER ENTITY)
-        let entity_transaction_nft = self.entity_transaction_nft(&entity_name);
+        let entity_transaction_nft = self.entity_transaction_nft(entity_id);
         let existing_nonce_opt = entity_transaction_nft.get();
         
-        let target_nonce = if existing_nonce_opt == 0 {
+        let receipt_nonce = if existing_nonce_opt == 0 {
             // No NFT exists - create new one
         let current_nonce = self.nft_nonce().get();
         let new_nonce = current_nonce + 1;
@@ -621,9 +1229,9 @@ pub trait PhilanthrifyFactory {
 
             // Create attributes with aggregated data
             let attrs = self.create_transaction_nft_attributes_aggregated(
-            &entity_name,
-            &entity_type,
-                &new_total_amount,
+            entity_name,
+            entity_type,
+                &spent_totals,
                 new_transaction_count,
             &category,
             &description,
@@ -634,12 +1242,7 @@ pub trait PhilanthrifyFactory {
 
         // Add image URIs: first = display image on explorer. User upload = first if provided; else default badge.
         let default_transaction_image = ManagedBuffer::from(b"https://ipfs.io/ipfs/bafybeicqtbhfnonjy7hfddbsd6cpbeu3vbjk3ysjaddy7m2dnpng52hmae");
-        let mut uris = ManagedVec::new();
-        if !user_image_uri.is_empty() {
-            if let Some(formatted_uri) = self.format_user_image_uri(&user_image_uri) {
-                uris.push(formatted_uri);
-            }
-        }
+        let mut uris = user_image_uris;
         if uris.is_empty() {
             uris.push(default_transaction_image);
         }
@@ -655,7 +1258,7 @@ pub trait PhilanthrifyFactory {
         );
 
             // Store the nonce
-            entity_transaction_nft.set(&created_nonce);
+            entity_transaction_nft.set(created_nonce);
         self.nft_nonce().set(new_nonce);
 
         let mut stats = self.global_statistics().get();
@@ -666,11 +1269,14 @@ pub trait PhilanthrifyFactory {
             let metadata = NftMetadataRecord {
                 nft_nonce: created_nonce,
                 donor_address: entity_owner.clone(),
+                entity_id,
                 entity_name: entity_name.clone(),
                 entity_type: entity_type.clone(),
                 donation_count: new_transaction_count,
                 tier_level: 0,
                 total_amount: new_total_amount.clone(),
+                last_token_identifier: token_identifier.clone(),
+                last_amount: display_amount.clone(),
                 last_updated: self.blockchain().get_block_timestamp(),
                 is_on_contract: true,
             };
@@ -683,9 +1289,9 @@ pub trait PhilanthrifyFactory {
 
             // Create updated attributes with new aggregated data
             let updated_attrs = self.create_transaction_nft_attributes_aggregated(
-                &entity_name,
-                &entity_type,
-                &new_total_amount,
+                entity_name,
+                entity_type,
+                &spent_totals,
                 new_transaction_count,
                 &category,
                 &description,
@@ -709,11 +1315,9 @@ pub trait PhilanthrifyFactory {
                     &updated_attrs,
                 );
                 
-                // Add user image URI if provided (appends to history)
-                if !user_image_uri.is_empty() {
-                    if let Some(formatted_uri) = self.format_user_image_uri(&user_image_uri) {
-                        self.send().nft_add_uri(&nft_token_id, existing_nonce, formatted_uri);
-                    }
+                // Add user image URIs if provided (appends to history)
+                for formatted_uri in user_image_uris.iter() {
+                    self.send().nft_add_uri(&nft_token_id, existing_nonce, (*formatted_uri).clone());
                 }
             }
             // If NFT is not on contract, skip update (it may have been transferred to wallet)
@@ -726,11 +1330,14 @@ pub trait PhilanthrifyFactory {
                 NftMetadataRecord {
                     nft_nonce: existing_nonce,
                     donor_address: entity_owner.clone(),
+                    entity_id,
                     entity_name: entity_name.clone(),
                     entity_type: entity_type.clone(),
                     donation_count: new_transaction_count,
                     tier_level: 0,
                     total_amount: new_total_amount.clone(),
+                    last_token_identifier: token_identifier.clone(),
+                    last_amount: display_amount.clone(),
                     last_updated: self.blockchain().get_block_timestamp(),
                     is_on_contract,
                 }
@@ -739,6 +1346,8 @@ pub trait PhilanthrifyFactory {
                 let mut existing_metadata = metadata_mapper.get();
                 existing_metadata.donation_count = new_transaction_count;
                 existing_metadata.total_amount = new_total_amount.clone();
+                existing_metadata.last_token_identifier = token_identifier.clone();
+                existing_metadata.last_amount = display_amount.clone();
                 existing_metadata.last_updated = self.blockchain().get_block_timestamp();
                 existing_metadata.is_on_contract = is_on_contract;
                 existing_metadata
@@ -750,7 +1359,97 @@ pub trait PhilanthrifyFactory {
             existing_nonce
         };
 
-        self.transaction_nft_minted(&entity_name, &entity_type, target_nonce);
+        for item in items.iter() {
+            self.record_spending(entity_id, &item, receipt_nonce, token_identifier, recipient);
+        }
+        receipt_nonce
+    }
+
+    /// Mints a receipt for a single spending transaction; it stays on the factory like the aggregated one.
+    fn mint_spending_receipt(
+        &self,
+        entity: &EntityInfo<Self::Api>,
+        entity_owner: &ManagedAddress,
+        item: &TransactionItem<Self::Api>,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        transaction_number: u64,
+    ) -> u64 {
+        let nft_token_id = self.global_nft_collection().get();
+        let attrs = self.create_transaction_nft_attributes(
+            &entity.name,
+            &entity.entity_type,
+            transaction_number,
+            &item.display_amount,
+            token_identifier,
+            &item.category,
+            &item.description,
+        );
+
+        let mut uris = ManagedVec::new();
+        if !item.user_image_uri.is_empty() {
+            if let Some(formatted_uri) = self.format_user_image_uri(&item.user_image_uri) {
+                uris.push(formatted_uri);
+            }
+        }
+        if uris.is_empty() {
+            uris.push(ManagedBuffer::from(b"https://ipfs.io/ipfs/bafybeicqtbhfnonjy7hfddbsd6cpbeu3vbjk3ysjaddy7m2dnpng52hmae"));
+        }
+
+        let created_nonce = self.send().esdt_nft_create(
+            &nft_token_id,
+            &BigUint::from(1u32),
+            &ManagedBuffer::from(b"Philanthrify Spending Receipt"),
+            &BigUint::from(500u32),
+            &ManagedBuffer::new(),
+            &attrs,
+            &uris,
+        );
+        self.nft_nonce().update(|nonce| *nonce += 1);
+        let mut stats = self.global_statistics().get();
+        stats.total_nfts_minted += 1;
+        self.global_statistics().set(&stats);
+
+        let metadata = NftMetadataRecord {
+            nft_nonce: created_nonce,
+            donor_address: entity_owner.clone(),
+            entity_id: entity.id,
+            entity_name: entity.name.clone(),
+            entity_type: entity.entity_type.clone(),
+            donation_count: transaction_number,
+            tier_level: 0,
+            total_amount: item.display_amount.clone(),
+            last_token_identifier: token_identifier.clone(),
+            last_amount: item.display_amount.clone(),
+            last_updated: self.blockchain().get_block_timestamp(),
+            is_on_contract: true,
+        };
+        self.nft_metadata_record(created_nonce).set(&metadata);
+        created_nonce
+    }
+
+    fn record_spending(
+        &self,
+        entity_id: u64,
+        item: &TransactionItem<Self::Api>,
+        receipt_nonce: u64,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        recipient: &ManagedAddress,
+    ) {
+        let records = self.spending_records(entity_id);
+        let id = records.len() as u64 + 1;
+        let record = SpendingRecord {
+            id,
+            entity_id,
+            amount: item.display_amount.clone(),
+            category: item.category.clone(),
+            description: item.description.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+            receipt_nonce,
+            token_identifier: token_identifier.clone(),
+            recipient: recipient.clone(),
+        };
+        self.spending_records(entity_id).push(&record);
+        self.spending_ids_by_category(entity_id, &item.category).push(&id);
     }
 
     // ============================================================
@@ -803,266 +1502,423 @@ pub trait PhilanthrifyFactory {
         entity_name: &ManagedBuffer,
         entity_type: &ManagedBuffer,
         donation_count_to_entity: u64,
-        tier_level: u64,
-        tier_name: &ManagedBuffer,
+        tier: &TierConfig<Self::Api>,
         user_tags: &ManagedVec<Self::Api, ManagedBuffer>,
-        total_amount: &BigUint,  // Used for donated amount tag
+        token_totals: &ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>>,  // Used for donated amount tag
+        last_token_identifier: &EgldOrEsdtTokenIdentifier,
+        last_amount: &BigUint,
         patron_rank: Option<u64>,
         recurring_pattern: Option<&RecurringPattern>,
         tier_image_uri: Option<ManagedBuffer>,  // Optional; when set, add ;image:url for explorers/frontends
     ) -> ManagedBuffer {
-        let mut attributes = ManagedBuffer::new();
-
         // ALWAYS include metadata reference - NEVER removed on updates
-        // Format: metadata:ipfsCID;tags:tag1,tag2,tag3
+        // Format: metadata:ipfsCID;tags:tag1,tag2,tag3 (tags: only when the CID is not set)
         // Note: CID points directly to the JSON file (no /metadata.json needed)
-        let ipfs_cid = self.donor_nft_ipfs_cid().get();
-        if !ipfs_cid.is_empty() {
-            attributes.append(&ManagedBuffer::from(b"metadata:"));
-            attributes.append(&ipfs_cid);
-            attributes.append(&ManagedBuffer::from(b";tags:"));
-        } else {
-            // Fallback: if IPFS CID not set, use tags: format only
-            attributes.append(&ManagedBuffer::from(b"tags:"));
-        }
-
-        // Build tags list (comma-separated)
-        let mut needs_comma = false;
-
-        // Add user tags if any
-        if user_tags.len() > 0 {
-            for tag in user_tags.iter() {
-                if needs_comma {
-                    attributes.append(&ManagedBuffer::from(b","));
-                }
-                attributes.append(&tag);
-                needs_comma = true;
-            }
-        }
+        let mut attributes = NftAttributes::new(&self.donor_nft_ipfs_cid().get());
 
-        // Add dynamic tags based on donation info
-        if needs_comma {
-            attributes.append(&ManagedBuffer::from(b","));
+        for tag in user_tags.iter() {
+            attributes.tag(&tag);
         }
-        attributes.append(&ManagedBuffer::from(b"donation"));
-        attributes.append(&self.u64_to_buffer(donation_count_to_entity));
-
-        attributes.append(&ManagedBuffer::from(b",donated$"));
-        attributes.append(&self.u64_to_buffer(total_amount.to_u64().unwrap_or(0)));
 
-        attributes.append(&ManagedBuffer::from(b","));
-        attributes.append(entity_name);
-
-        attributes.append(&ManagedBuffer::from(b","));
-        attributes.append(entity_type);
+        // Dynamic tags based on donation info
+        let total_str = self.format_token_totals(token_totals);
+        let last_token_name = last_token_identifier.clone().into_name();
+        attributes.tag_with_value(b"donation", &self.u64_to_buffer(donation_count_to_entity));
+        attributes.tag_with_value(b"donated$", &total_str);
+        attributes.tag_with_value(b"token:", &last_token_name);
+        attributes.tag(entity_name);
+        attributes.tag(entity_type);
 
         // Tier (position 5): #bronze so it shows
-        attributes.append(&ManagedBuffer::from(b","));
-        attributes.append(&self.get_tier_tag_lower(tier_level));
+        attributes.tag(&tier.tag);
 
         // Patron as 6th tag (explorer only shows first 6): #patron_1 or #supporter so patron shows
         if let Some(rank) = patron_rank {
-            attributes.append(&ManagedBuffer::from(b",patron"));
-            attributes.append(&ManagedBuffer::from(b","));
-            attributes.append(&self.get_patron_rank_tag(rank));
-            attributes.append(&ManagedBuffer::from(b",patron_rank:"));
-            attributes.append(&self.u64_to_buffer(rank));
+            attributes.tag_bytes(b"patron");
+            attributes.tag(&self.get_patron_rank_tag(rank));
+            attributes.tag_with_value(b"patron_rank:", &self.u64_to_buffer(rank));
         } else {
-            attributes.append(&ManagedBuffer::from(b",supporter"));
-            attributes.append(&ManagedBuffer::from(b",patron_rank:0"));
+            attributes.tag_bytes(b"supporter");
+            attributes.tag_bytes(b"patron_rank:0");
         }
 
         // tier:Name after patron (still in string for API / metadata JSON)
-        attributes.append(&ManagedBuffer::from(b",tier:"));
-        attributes.append(tier_name);
+        attributes.tag_with_value(b"tier:", &tier.name);
 
         // Add recurring pattern tags
         if let Some(pattern) = recurring_pattern {
             if pattern.monthly_streak > 1 {
-                attributes.append(&ManagedBuffer::from(b",recurring_monthly:"));
-                attributes.append(&self.u64_to_buffer(pattern.monthly_streak));
+                attributes.tag_with_value(b"recurring_monthly:", &self.u64_to_buffer(pattern.monthly_streak));
             }
             if pattern.quarterly_streak > 1 {
-                attributes.append(&ManagedBuffer::from(b",recurring_quarterly:"));
-                attributes.append(&self.u64_to_buffer(pattern.quarterly_streak));
+                attributes.tag_with_value(b"recurring_quarterly:", &self.u64_to_buffer(pattern.quarterly_streak));
             }
         }
 
         // Always add default tags
-        attributes.append(&ManagedBuffer::from(b",philanthrify"));
-        attributes.append(&ManagedBuffer::from(b",charity"));
-        attributes.append(&ManagedBuffer::from(b",blockchain"));
-        attributes.append(&ManagedBuffer::from(b",transparency"));
-        attributes.append(&ManagedBuffer::from(b",impact"));
+        for tag in [&b"philanthrify"[..], b"charity", b"blockchain", b"transparency", b"impact"] {
+            attributes.tag_bytes(tag);
+        }
 
         // Gamification: add image URI for UIs that read attributes (tier-specific display image)
         if let Some(uri) = tier_image_uri {
-            if !uri.is_empty() {
-                attributes.append(&ManagedBuffer::from(b";image:"));
-                attributes.append(&uri);
-            }
+            attributes.image(&uri);
         }
 
         // All traits on-chain: Patron and Patron Rank right after Tier so explorer shows them in Attributes
-        let total_str = self.u64_to_buffer(total_amount.to_u64().unwrap_or(0));
         let monthly = recurring_pattern.map(|p| p.monthly_streak).unwrap_or(0);
         let quarterly = recurring_pattern.map(|p| p.quarterly_streak).unwrap_or(0);
         // No website link; platform/project attributes: Platform, Blockchain, Impact, Transparency, Badge, Status, Tier, Patron...
-        attributes.append(&ManagedBuffer::from(b";traits:[{\"trait_type\":\"Platform\",\"value\":\"Philanthrify\"},{\"trait_type\":\"Blockchain\",\"value\":\"MultiversX\"},{\"trait_type\":\"Badge Type\",\"value\":\"Donor Badge\"},{\"trait_type\":\"Status\",\"value\":\"Active\"},{\"trait_type\":\"Impact\",\"value\":\"Verified\"},{\"trait_type\":\"Transparency\",\"value\":\"On-chain\"},{\"trait_type\":\"Tier\",\"value\":\""));
-        attributes.append(tier_name);
-        // Patron and Patron Rank immediately after Tier so they show in explorer Attributes
+        attributes.add_trait_bytes(b"Platform", b"Philanthrify");
+        attributes.add_trait_bytes(b"Blockchain", b"MultiversX");
+        attributes.add_trait_bytes(b"Badge Type", b"Donor Badge");
+        attributes.add_trait_bytes(b"Status", b"Active");
+        attributes.add_trait_bytes(b"Impact", b"Verified");
+        attributes.add_trait_bytes(b"Transparency", b"On-chain");
+        attributes.add_trait(b"Tier", &tier.name);
         if let Some(rank) = patron_rank {
-            attributes.append(&ManagedBuffer::from(b"\"},{\"trait_type\":\"Patron\",\"value\":\"Yes\"},{\"trait_type\":\"Patron Rank\",\"value\":\""));
-            attributes.append(&self.u64_to_buffer(rank));
-            attributes.append(&ManagedBuffer::from(b"\"},{\"trait_type\":\"Donation Count\",\"value\":\""));
+            attributes.add_trait_bytes(b"Patron", b"Yes");
+            attributes.add_trait(b"Patron Rank", &self.u64_to_buffer(rank));
         } else {
-            attributes.append(&ManagedBuffer::from(b"\"},{\"trait_type\":\"Patron\",\"value\":\"No\"},{\"trait_type\":\"Patron Rank\",\"value\":\"-\"},{\"trait_type\":\"Donation Count\",\"value\":\""));
+            attributes.add_trait_bytes(b"Patron", b"No");
+            attributes.add_trait_bytes(b"Patron Rank", b"-");
         }
-        attributes.append(&self.u64_to_buffer(donation_count_to_entity));
-        attributes.append(&ManagedBuffer::from(b"\"},{\"trait_type\":\"Total Donated\",\"value\":\""));
-        attributes.append(&total_str);
-        attributes.append(&ManagedBuffer::from(b"\"},{\"trait_type\":\"Monthly Streak\",\"value\":\""));
-        attributes.append(&self.u64_to_buffer(monthly));
-        attributes.append(&ManagedBuffer::from(b"\"},{\"trait_type\":\"Quarterly Streak\",\"value\":\""));
-        attributes.append(&self.u64_to_buffer(quarterly));
-        attributes.append(&ManagedBuffer::from(b"\"}]"));
-
-        attributes
+        attributes.add_trait(b"Donation Count", &self.u64_to_buffer(donation_count_to_entity));
+        attributes.add_trait(b"Total Donated", &total_str);
+        attributes.add_trait(b"Last Donation", &self.format_token_amount(last_amount, last_token_identifier));
+        attributes.add_trait(b"Last Donation Token", &last_token_name);
+        attributes.add_trait(b"Monthly Streak", &self.u64_to_buffer(monthly));
+        attributes.add_trait(b"Quarterly Streak", &self.u64_to_buffer(quarterly));
+
+        attributes.build()
     }
 
+    /// Attributes of a per-transaction receipt: the expense's own amount, category and description.
     fn create_transaction_nft_attributes(
         &self,
         entity_name: &ManagedBuffer,
         entity_type: &ManagedBuffer,
+        transaction_number: u64,
         amount: &BigUint,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
         category: &ManagedBuffer,
         description: &ManagedBuffer,
     ) -> ManagedBuffer {
-        let mut attributes = ManagedBuffer::new();
-
-        attributes.append(&ManagedBuffer::from(b"["));
-
-        attributes.append(&ManagedBuffer::from(b"{\"trait_type\":\"Type\",\"value\":\"Transaction\"},"));
-
-        attributes.append(&ManagedBuffer::from(b"{\"trait_type\":\"Entity\",\"value\":\""));
-        attributes.append(entity_name);
-        attributes.append(&ManagedBuffer::from(b"\"},"));
-
-        attributes.append(&ManagedBuffer::from(b"{\"trait_type\":\"EntityType\",\"value\":\""));
-        attributes.append(entity_type);
-        attributes.append(&ManagedBuffer::from(b"\"},"));
-
-        attributes.append(&ManagedBuffer::from(b"{\"trait_type\":\"Amount\",\"value\":\""));
-        attributes.append(&self.u64_to_buffer(amount.to_u64().unwrap_or(0)));
-        attributes.append(&ManagedBuffer::from(b"\"},"));
-
-        attributes.append(&ManagedBuffer::from(b"{\"trait_type\":\"Category\",\"value\":\""));
-        attributes.append(category);
-        attributes.append(&ManagedBuffer::from(b"\"},"));
-
-        attributes.append(&ManagedBuffer::from(b"{\"trait_type\":\"Description\",\"value\":\""));
-        attributes.append(description);
-        attributes.append(&ManagedBuffer::from(b"\"}"));
-
-        attributes.append(&ManagedBuffer::from(b"]"));
-
-        attributes
+        let mut attributes = NftAttributes::new(&self.transaction_nft_ipfs_cid().get());
+        attributes.tag_with_value(b"transaction", &self.u64_to_buffer(transaction_number));
+        attributes.tag_with_value(b"spent$", &self.format_tag_amount(amount, token_identifier));
+        attributes.tag(category);
+        attributes.tag(entity_name);
+        attributes.tag_bytes(b"receipt");
+        attributes.tag_bytes(b"philanthrify");
+        attributes.tag(entity_type);
+        attributes.tag_bytes(b"transparency");
+        attributes.add_trait_bytes(b"Type", b"Transaction");
+        attributes.add_trait(b"Entity", entity_name);
+        attributes.add_trait(b"EntityType", entity_type);
+        attributes.add_trait(b"Amount", &self.format_token_amount(amount, token_identifier));
+        attributes.add_trait(b"Category", category);
+        attributes.add_trait(b"Description", description);
+        attributes.build()
     }
 
     fn create_transaction_nft_attributes_aggregated(
         &self,
         entity_name: &ManagedBuffer,
         entity_type: &ManagedBuffer,
-        total_amount: &BigUint,
+        spent_totals: &ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>>,
         transaction_count: u64,
         _latest_category: &ManagedBuffer,  // Not used - removed from attributes per user request
         _latest_description: &ManagedBuffer,  // Not used - removed from attributes per user request
     ) -> ManagedBuffer {
-        let mut attributes = ManagedBuffer::new();
-
         // ALWAYS include metadata reference - NEVER removed on updates
-        // Format: metadata:ipfsCID;tags:tag1,tag2,tag3
-        // Note: CID points directly to the JSON file (no /metadata.json needed)
-        let ipfs_cid = self.transaction_nft_ipfs_cid().get();
-        if !ipfs_cid.is_empty() {
-            attributes.append(&ManagedBuffer::from(b"metadata:"));
-            attributes.append(&ipfs_cid);
-            attributes.append(&ManagedBuffer::from(b";tags:"));
+        // Format: metadata:ipfsCID;tags:tag1,tag2,tag3 (tags: only when the CID is not set)
+        let mut attributes = NftAttributes::new(&self.transaction_nft_ipfs_cid().get());
+
+        // Build tags: dynamic (count, amount, name) + platform (receipt, philanthrify, entity type, transparency, impact)
+        attributes.tag_with_value(b"transaction", &self.u64_to_buffer(transaction_count));
+        attributes.tag_with_value(b"spent$", &self.format_token_totals(spent_totals));
+        attributes.tag(entity_name);
+        attributes.tag_bytes(b"receipt");
+        attributes.tag_bytes(b"philanthrify");
+        attributes.tag(entity_type);
+        attributes.tag_bytes(b"transparency");
+        attributes.tag_bytes(b"impact");
+
+        attributes.build()
+    }
+
+    fn amount_format_or_default(&self) -> AmountFormat {
+        let format_mapper = self.amount_format();
+        if format_mapper.is_empty() {
+            AmountFormat { decimals: 18, precision: 4 }
         } else {
-            // Fallback: if IPFS CID not set, use tags: format only
-            attributes.append(&ManagedBuffer::from(b"tags:"));
+            format_mapper.get()
         }
+    }
 
-        // Build tags: dynamic (count, amount, name) + platform (receipt, philanthrify, entity type, transparency, impact)
-        attributes.append(&ManagedBuffer::from(b"transaction"));
-        attributes.append(&self.u64_to_buffer(transaction_count));
-        attributes.append(&ManagedBuffer::from(b",spent$"));
-        attributes.append(&self.u64_to_buffer(total_amount.to_u64().unwrap_or(0)));
-        attributes.append(&ManagedBuffer::from(b","));
-        attributes.append(entity_name);
-        attributes.append(&ManagedBuffer::from(b",receipt"));
-        attributes.append(&ManagedBuffer::from(b",philanthrify"));
-        attributes.append(&ManagedBuffer::from(b","));
-        attributes.append(entity_type);
-        attributes.append(&ManagedBuffer::from(b",transparency"));
-        attributes.append(&ManagedBuffer::from(b",impact"));
-
-        attributes
+    /// Total or receipt amount in whole units, e.g. "12.5".
+    fn format_amount(&self, amount: &BigUint) -> ManagedBuffer {
+        let format = self.amount_format_or_default();
+        amount::format_amount(amount, format.decimals, format.precision)
+    }
+
+    /// Amount in the token's own decimals (platform decimals when unset), without the ticker
+    fn format_in_token_decimals(&self, amount: &BigUint, token: &EgldOrEsdtTokenIdentifier) -> ManagedBuffer {
+        let format = self.amount_format_or_default();
+        let decimals = self.token_decimals(token).get().unwrap_or(format.decimals);
+        amount::format_amount(amount, decimals, format.precision)
+    }
+
+    /// Single-token amount with its ticker, e.g. "12.5 EGLD"; tokens without configured decimals use the platform ones.
+    fn format_token_amount(&self, amount: &BigUint, token: &EgldOrEsdtTokenIdentifier) -> ManagedBuffer {
+        let mut formatted = self.format_in_token_decimals(amount, token);
+        formatted.append_bytes(b" ");
+        if token.is_egld() {
+            formatted.append_bytes(b"EGLD");
+        } else {
+            formatted.append(&token.clone().unwrap_esdt().ticker());
+        }
+        formatted
+    }
+
+    /// Amount for a `donated$`/`spent$` tag: a bare number for EGLD, with the ticker for any other token
+    fn format_tag_amount(&self, amount: &BigUint, token: &EgldOrEsdtTokenIdentifier) -> ManagedBuffer {
+        if token.is_egld() {
+            self.format_in_token_decimals(amount, token)
+        } else {
+            self.format_token_amount(amount, token)
+        }
+    }
+
+    /// Per-token totals joined with '+' ("12.5 EGLD+1000 USDC"); an EGLD-only total stays a bare number
+    fn format_token_totals(&self, totals: &ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>>) -> ManagedBuffer {
+        if totals.len() == 1 {
+            let only = totals.get(0);
+            return self.format_tag_amount(&only.amount, &only.token_identifier);
+        }
+        let mut formatted = ManagedBuffer::new();
+        for (i, total) in totals.iter().enumerate() {
+            if i > 0 {
+                formatted.append_bytes(b"+");
+            }
+            formatted.append(&self.format_token_amount(&total.amount, &total.token_identifier));
+        }
+        formatted
+    }
+
+    /// What the donor gave the entity, per token. Donors from before per-token tracking fall back to their
+    /// recorded total as EGLD.
+    fn donor_token_payments(
+        &self,
+        donor: &ManagedAddress,
+        entity_id: u64,
+    ) -> ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>> {
+        let mut totals = ManagedVec::new();
+        for token in self.donor_tokens_to_entity(donor, entity_id).iter() {
+            let amount = self.donor_token_amount_to_entity(donor, entity_id, &token).get();
+            totals.push(EgldOrEsdtTokenPayment::new(token, 0, amount));
+        }
+        if totals.is_empty() {
+            let legacy_total = self.donor_total_amount_to_entity(donor, entity_id).get();
+            totals.push(EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, legacy_total));
+        }
+        totals
+    }
+
+    /// What the entity recorded as spent, per token, for its receipt: EGLD first (display-only and paid), then each ESDT
+    fn entity_spending_totals(&self, entity_id: u64) -> ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>> {
+        let mut totals = ManagedVec::new();
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        let egld_total = self.entity_transaction_total(entity_id).get() + self.entity_spent_by_token(entity_id, &egld).get();
+        let paid_tokens = self.entity_spent_tokens(entity_id);
+        if egld_total > 0u32 || paid_tokens.is_empty() {
+            totals.push(EgldOrEsdtTokenPayment::new(egld, 0, egld_total));
+        }
+        for token in paid_tokens.iter().filter(|token| token.is_esdt()) {
+            let amount = self.entity_spent_by_token(entity_id, &token).get();
+            totals.push(EgldOrEsdtTokenPayment::new(token, 0, amount));
+        }
+        totals
+    }
+
+    /// Token and amount of the latest donation behind a badge (EGLD/0 for legacy NFTs without metadata)
+    fn get_last_donation(&self, nft_nonce: u64) -> (EgldOrEsdtTokenIdentifier, BigUint) {
+        let metadata_mapper = self.nft_metadata_record(nft_nonce);
+        if metadata_mapper.is_empty() {
+            (EgldOrEsdtTokenIdentifier::egld(), BigUint::zero())
+        } else {
+            let metadata = metadata_mapper.get();
+            (metadata.last_token_identifier, metadata.last_amount)
+        }
     }
 
     // ============================================================
-    // TIER SYSTEM (SIMPLIFIED - 4 TIERS ONLY)
+    // TIER SYSTEM - Ordered tier table, optionally overridden per entity
     // ============================================================
 
-    fn calculate_tier_for_entity(&self, donation_count: u64) -> u64 {
-        // Simplified tier system with 4 tiers (no Legendary)
-        if donation_count >= 11 {
-            4u64  // Platinum (11+ donations)
-        } else if donation_count >= 6 {
-            3u64  // Gold (6-10 donations)
-        } else if donation_count >= 3 {
-            2u64  // Silver (3-5 donations)
+    /// Bronze/Silver/Gold/Platinum at 1/3/6/11 donations, keeping images set through the old fixed-tier endpoint.
+    fn seed_default_tiers(&self) {
+        let defaults: [(&[u8], &[u8], u64); 4] = [
+            (b"Bronze", b"bronze", 1),
+            (b"Silver", b"silver", 3),
+            (b"Gold", b"gold", 6),
+            (b"Platinum", b"platinum", 11),
+        ];
+        let mut tiers = self.tiers();
+        for (index, (name, tag, min_donations)) in defaults.iter().enumerate() {
+            let tier_level = index as u64 + 1;
+            let legacy_image = match tier_level {
+                1 => self.tier_bronze_image_uri(),
+                2 => self.tier_silver_image_uri(),
+                3 => self.tier_gold_image_uri(),
+                _ => self.tier_platinum_image_uri(),
+            };
+            let image_uri = if legacy_image.is_empty() {
+                self.default_tier_image_uri(tier_level)
+            } else {
+                legacy_image.take()
+            };
+            tiers.push(&TierConfig {
+                name: ManagedBuffer::from(*name),
+                tag: ManagedBuffer::from(*tag),
+                image_uri,
+                min_donations: *min_donations,
+                min_amount: BigUint::zero(),
+            });
+        }
+    }
+
+    /// The entity's own tier table when set, otherwise the platform-wide one.
+    fn tier_table(&self, entity_id: u64) -> VecMapper<TierConfig<Self::Api>> {
+        let entity_tiers = self.entity_tiers(entity_id);
+        if entity_tiers.is_empty() {
+            self.tiers()
         } else {
-            1u64  // Bronze (1-2 donations)
+            entity_tiers
         }
     }
 
-    fn get_tier_name(&self, tier: u64) -> ManagedBuffer {
-        match tier {
-            4 => ManagedBuffer::from(b"Platinum"),
-            3 => ManagedBuffer::from(b"Gold"),
-            2 => ManagedBuffer::from(b"Silver"),
-            _ => ManagedBuffer::from(b"Bronze"),
+    fn tier_policy_for_entity(&self, entity_id: u64) -> TierPolicy {
+        let entity_policy = self.entity_tier_policy(entity_id);
+        if !entity_policy.is_empty() {
+            return entity_policy.get();
+        }
+        let platform_policy = self.tier_policy();
+        if platform_policy.is_empty() {
+            TierPolicy::DonationCount
+        } else {
+            platform_policy.get()
         }
     }
 
-    /// Lowercase tier tag for explorer Tags (#bronze, #silver, #gold, #platinum)
-    fn get_tier_tag_lower(&self, tier: u64) -> ManagedBuffer {
-        match tier {
-            4 => ManagedBuffer::from(b"platinum"),
-            3 => ManagedBuffer::from(b"gold"),
-            2 => ManagedBuffer::from(b"silver"),
-            _ => ManagedBuffer::from(b"bronze"),
+    fn load_tier_ladder(&self, entity_id: u64) -> TierLadder<Self::Api> {
+        let mut tiers = ManagedVec::new();
+        for tier in self.tier_table(entity_id).iter() {
+            tiers.push(tier);
         }
+        TierLadder { tiers, policy: self.tier_policy_for_entity(entity_id) }
     }
 
-    /// Patron rank as single tag so explorer shows #patron_1 .. #patron_10 (like #bronze)
-    fn get_patron_rank_tag(&self, rank: u64) -> ManagedBuffer {
-        match rank {
-            1 => ManagedBuffer::from(b"patron_1"),
-            2 => ManagedBuffer::from(b"patron_2"),
-            3 => ManagedBuffer::from(b"patron_3"),
-            4 => ManagedBuffer::from(b"patron_4"),
-            5 => ManagedBuffer::from(b"patron_5"),
-            6 => ManagedBuffer::from(b"patron_6"),
-            7 => ManagedBuffer::from(b"patron_7"),
-            8 => ManagedBuffer::from(b"patron_8"),
-            9 => ManagedBuffer::from(b"patron_9"),
-            _ => ManagedBuffer::from(b"patron_10"),
+    /// Highest tier (1-based) the donor reached under the entity's tier policy; the first tier is the floor.
+    fn calculate_tier_for_entity(&self, entity_id: u64, donation_count: u64, total_amount: &BigUint) -> u64 {
+        self.tier_level_in(&self.load_tier_ladder(entity_id), donation_count, total_amount)
+    }
+
+    fn tier_level_in(&self, ladder: &TierLadder<Self::Api>, donation_count: u64, total_amount: &BigUint) -> u64 {
+        let mut tier_level = 1u64;
+        for i in 1..ladder.tiers.len() {
+            if !self.is_tier_reached(&ladder.policy, &ladder.tiers.get(i), donation_count, total_amount) {
+                break;
+            }
+            tier_level = i as u64 + 1;
+        }
+        tier_level
+    }
+
+    fn is_tier_reached(
+        &self,
+        policy: &TierPolicy,
+        tier: &TierConfig<Self::Api>,
+        donation_count: u64,
+        total_amount: &BigUint,
+    ) -> bool {
+        match policy {
+            TierPolicy::DonationCount => donation_count >= tier.min_donations,
+            TierPolicy::TotalAmount => total_amount >= &tier.min_amount,
+            TierPolicy::Weighted { count_weight_bps } => {
+                // Progress towards each threshold in basis points; a zero threshold counts as fully met
+                let count_progress = if tier.min_donations == 0 {
+                    BigUint::from(BPS)
+                } else {
+                    BigUint::from(donation_count) * BPS / tier.min_donations
+                };
+                let amount_progress = if tier.min_amount == 0u32 {
+                    BigUint::from(BPS)
+                } else {
+                    total_amount * BPS / &tier.min_amount
+                };
+                let progress = count_progress * *count_weight_bps + amount_progress * (BPS - count_weight_bps);
+                progress >= BigUint::from(BPS) * BPS
+            },
+        }
+    }
+
+    fn get_tier(&self, entity_id: u64, tier_level: u64) -> TierConfig<Self::Api> {
+        self.tier_in(&self.load_tier_ladder(entity_id), tier_level)
+    }
+
+    fn tier_in(&self, ladder: &TierLadder<Self::Api>, tier_level: u64) -> TierConfig<Self::Api> {
+        require!(!ladder.tiers.is_empty(), "Tier table not configured");
+        let index = (tier_level as usize).clamp(1, ladder.tiers.len());
+        ladder.tiers.get(index - 1).clone()
+    }
+
+    fn get_tier_image_uri(&self, tier: &TierConfig<Self::Api>) -> Option<ManagedBuffer> {
+        if tier.image_uri.is_empty() {
+            None
+        } else {
+            Some(tier.image_uri.clone())
+        }
+    }
+
+    fn require_valid_tier(&self, tier: &TierConfig<Self::Api>) {
+        require!(!tier.name.is_empty(), "Tier name cannot be empty");
+        require!(!tier.tag.is_empty(), "Tier tag cannot be empty");
+        require!(!tier.image_uri.is_empty(), "Tier image URI cannot be empty");
+    }
+
+    /// Each tier must ask for at least as much as the one below it, and strictly more on some threshold.
+    fn require_tier_order(&self, tiers: &VecMapper<TierConfig<Self::Api>>, index: usize) {
+        let tier = tiers.get(index);
+        if index > 1 {
+            require!(self.is_tier_above(&tier, &tiers.get(index - 1)), "Tier thresholds must increase");
+        }
+        if index < tiers.len() {
+            require!(self.is_tier_above(&tiers.get(index + 1), &tier), "Tier thresholds must increase");
+        }
+    }
+
+    fn is_tier_above(&self, upper: &TierConfig<Self::Api>, lower: &TierConfig<Self::Api>) -> bool {
+        upper.min_donations >= lower.min_donations
+            && upper.min_amount >= lower.min_amount
+            && (upper.min_donations > lower.min_donations || upper.min_amount > lower.min_amount)
+    }
+
+    fn require_valid_tier_policy(&self, policy: &TierPolicy) {
+        if let TierPolicy::Weighted { count_weight_bps } = policy {
+            require!(*count_weight_bps <= BPS, "Count weight cannot exceed 10000 bps");
         }
     }
 
-    /// Hardcoded default tier image URLs (ipfs.io) so contract works with zero config. Owner can override via setTierImageUri.
+    /// Patron rank as single tag so explorer shows #patron_1, #patron_2, ... (like #bronze)
+    fn get_patron_rank_tag(&self, rank: u64) -> ManagedBuffer {
+        let mut tag = ManagedBuffer::from(b"patron_");
+        tag.append(&self.u64_to_buffer(rank));
+        tag
+    }
+
+    /// Hardcoded default tier image URLs (ipfs.io) used when seeding the default tier table.
     fn default_tier_image_uri(&self, tier: u64) -> ManagedBuffer {
         match tier {
             1 => ManagedBuffer::from(b"https://ipfs.io/ipfs/bafybeiabacxg5gtzrrobsgnc4ghpln2urz7hfnxalwwnnvuvo3figzurgy"),
@@ -1082,28 +1938,6 @@ pub trait PhilanthrifyFactory {
         }
     }
 
-    fn get_tier_base_image_uri(&self, tier: u64) -> Option<ManagedBuffer> {
-        match tier {
-            1 => {
-                let mapper = self.tier_bronze_image_uri();
-                if mapper.is_empty() { Some(self.default_tier_image_uri(1)) } else { Some(mapper.get()) }
-            },
-            2 => {
-                let mapper = self.tier_silver_image_uri();
-                if mapper.is_empty() { Some(self.default_tier_image_uri(2)) } else { Some(mapper.get()) }
-            },
-            3 => {
-                let mapper = self.tier_gold_image_uri();
-                if mapper.is_empty() { Some(self.default_tier_image_uri(3)) } else { Some(mapper.get()) }
-            },
-            4 => {
-                let mapper = self.tier_platinum_image_uri();
-                if mapper.is_empty() { Some(self.default_tier_image_uri(4)) } else { Some(mapper.get()) }
-            },
-            _ => None,
-        }
-    }
-
     fn get_patron_badge_uri(&self, rank: u64) -> Option<ManagedBuffer> {
         if rank == 1 {
             let mapper = self.patron_top1_image_uri();
@@ -1118,220 +1952,164 @@ pub trait PhilanthrifyFactory {
     // PROJECT PATRON SYSTEM
     // ============================================================
 
+    /// Places the donor on the entity leaderboard (top N, sorted by total then earliest first donation).
+    /// Totals only grow, so the donor can only move up: one O(N) pass, no temporary storage.
+    /// Returns the donor's rank if on the board, the donors it overtook and the donor pushed off the board, if any.
     fn check_and_update_patrons(
         &self,
         donor_address: &ManagedAddress,
-        entity_name: &ManagedBuffer,
+        entity_id: u64,
         total_amount: &BigUint,
-    ) -> Option<u64> {
-        // Get current patron list for this project
-        let mut patrons = self.project_patrons(&entity_name);
-        
-        // Check if donor is already a patron
-        let mut existing_index: Option<usize> = None;
-        for i in 1..=patrons.len() {
-            let patron = patrons.get(i);
-            if patron.donor_address == *donor_address {
-                existing_index = Some(i);
-                break;
-            }
+    ) -> PatronPlacement<Self::Api> {
+        let off_board = PatronPlacement { rank: None, shifted_to: 0, evicted: None };
+        self.entity_donors(entity_id).insert(donor_address.clone());
+        let since_mapper = self.donor_first_donation_timestamp(donor_address, entity_id);
+        if since_mapper.is_empty() {
+            since_mapper.set(self.blockchain().get_block_timestamp());
         }
 
-        // Update or add patron
-        let current_timestamp = self.blockchain().get_block_timestamp();
-        
-        if let Some(index) = existing_index {
-            // Update existing patron
+        let mut patrons = self.project_patrons(entity_id);
+        let mut evicted_donor = None;
+        let mut index = self.find_patron_index(&patrons, donor_address);
+        let mut record = if index > 0 {
             let mut patron = patrons.get(index);
             patron.total_amount = total_amount.clone();
-            // Rank will be recalculated below
-            patrons.set(index, &patron);
+            patron
         } else {
-            // Add new patron if list is not full
-            if patrons.len() < 10 {
-                let new_patron = PatronRecord {
-                    donor_address: donor_address.clone(),
-                    total_amount: total_amount.clone(),
-                    patron_rank: 0,  // Will be set after sorting
-                    since_timestamp: current_timestamp,
-                };
-                patrons.push(&new_patron);
+            // Gifts that count for nothing in the ranking unit (unrated tokens) do not earn a place
+            if *total_amount == 0u32 {
+                return off_board;
+            }
+            let candidate = PatronRecord {
+                donor_address: donor_address.clone(),
+                total_amount: total_amount.clone(),
+                patron_rank: 0,
+                since_timestamp: since_mapper.get(),
+            };
+            if patrons.len() < self.get_leaderboard_size(entity_id) {
+                patrons.push(&candidate);
             } else {
-                // Check if new donor qualifies (has more than lowest patron)
-                // Find the minimum amount patron
-                let mut min_amount_opt: Option<BigUint> = None;
-                let mut min_index = 0usize;
-                
-                for i in 1..=patrons.len() {
-                    let patron = patrons.get(i);
-                    if min_amount_opt.is_none() || patron.total_amount < *min_amount_opt.as_ref().unwrap() {
-                        min_amount_opt = Some(patron.total_amount.clone());
-                        min_index = i;
-                    }
-                }
-                
-                // Replace lowest patron if new donor has more
-                if let Some(min_amount) = min_amount_opt {
-                    if total_amount > &min_amount {
-                        let new_patron = PatronRecord {
-                            donor_address: donor_address.clone(),
-                            total_amount: total_amount.clone(),
-                            patron_rank: 0,
-                            since_timestamp: current_timestamp,
-                        };
-                        patrons.set(min_index, &new_patron);
-                    } else {
-                        // Don't qualify for patron status
-                        return None;
-                    }
-                } else {
-                    // No patrons yet, but list is full (shouldn't happen)
-                    return None;
+                let last = patrons.get(patrons.len());
+                if !self.ranks_above(&candidate, &last) {
+                    return off_board;
                 }
+                evicted_donor = Some(last.donor_address);
             }
-        }
+            index = patrons.len();
+            candidate
+        };
+        let start_index = index as u64;
 
-        // Sort patrons by total_amount (descending) and assign ranks
-        // Since we can't use Vec or ManagedVec with PatronRecord, we'll rebuild in sorted order
-        // by repeatedly finding the max and rebuilding the list directly
-        let patron_count = patrons.len();
-        
-        // Use temporary storage for sorted patrons
-        let mut sorted_patrons = self.temp_sorted_patrons();
-        sorted_patrons.clear();
-        
-        // Track which indices we've already processed
-        let mut processed: ManagedVec<Self::Api, u64> = ManagedVec::new();
-        
-        // Find max patron each time and add to sorted list
-        for rank in 1..=patron_count {
-            let mut max_idx = 0usize;
-            let mut max_amount_opt: Option<BigUint> = None;
-            
-            // Find max from unprocessed patrons
-            for i in 1..=patron_count {
-                // Check if already processed (ManagedVec is 0-based)
-                let mut already_processed = false;
-                let i_as_u64 = i as u64;
-                for j in 0..processed.len() {
-                    if processed.get(j) == i_as_u64 {
-                        already_processed = true;
-                        break;
-                    }
-                }
-                
-                if !already_processed {
-                    let patron = patrons.get(i);
-                    if max_amount_opt.is_none() || patron.total_amount > *max_amount_opt.as_ref().unwrap() {
-                        max_amount_opt = Some(patron.total_amount.clone());
-                        max_idx = i;
-                    }
-                }
-            }
-            
-            // Add max patron to sorted list with rank
-            if max_idx > 0 {
-                let mut max_patron = patrons.get(max_idx);
-                max_patron.patron_rank = rank as u64;
-                sorted_patrons.push(&max_patron);
-                processed.push(max_idx as u64);
+        // Shift everyone the donor overtakes one place down
+        while index > 1 {
+            let mut previous = patrons.get(index - 1);
+            if !self.ranks_above(&record, &previous) {
+                break;
             }
+            previous.patron_rank = index as u64;
+            patrons.set(index, &previous);
+            index -= 1;
         }
-        
-        // Clear original and rebuild from sorted
-        patrons.clear();
-        for i in 1..=sorted_patrons.len() {
-            let patron = sorted_patrons.get(i);
-            patrons.push(&patron);
-        }
-        
-        // Clear temporary storage
-        sorted_patrons.clear();
+        record.patron_rank = index as u64;
+        patrons.set(index, &record);
 
-        // Find and return rank for this donor
-        for i in 1..=patrons.len() {
-            let patron = patrons.get(i);
-            if patron.donor_address == *donor_address {
-                return Some(patron.patron_rank);
-            }
-        }
+        PatronPlacement { rank: Some(index as u64), shifted_to: start_index, evicted: evicted_donor }
+    }
 
-        None
+    /// Higher total wins; equal totals go to whoever donated to the entity first.
+    fn ranks_above(&self, patron: &PatronRecord<Self::Api>, other: &PatronRecord<Self::Api>) -> bool {
+        patron.total_amount > other.total_amount
+            || (patron.total_amount == other.total_amount && patron.since_timestamp < other.since_timestamp)
     }
 
-    fn get_donor_patron_rank(&self, donor_address: &ManagedAddress, entity_name: &ManagedBuffer) -> Option<u64> {
-        let patrons = self.project_patrons(&entity_name);
+    /// 1-based index of the donor on the leaderboard, 0 when not on it.
+    fn find_patron_index(&self, patrons: &VecMapper<PatronRecord<Self::Api>>, donor_address: &ManagedAddress) -> usize {
         for i in 1..=patrons.len() {
-            let patron = patrons.get(i);
-            if patron.donor_address == *donor_address {
-                return Some(patron.patron_rank);
+            if patrons.get(i).donor_address == *donor_address {
+                return i;
             }
         }
-        None
+        0
     }
 
-    /// When ranks change, update other patrons' NFTs (tags + patron image) so e.g. old #1 shows #patron_2.
-    fn update_other_patrons_nfts(
-        &self,
-        current_donor: &ManagedAddress,
-        entity_name: &ManagedBuffer,
-        entity_type: &ManagedBuffer,
-        nft_token_id: &TokenIdentifier<Self::Api>,
-    ) {
-        let patrons = self.project_patrons(entity_name);
-        let contract_address = self.blockchain().get_sc_address();
-        let empty_tags = ManagedVec::new();
+    fn get_leaderboard_size(&self, entity_id: u64) -> usize {
+        let size_mapper = self.entity_leaderboard_size(entity_id);
+        if size_mapper.is_empty() {
+            DEFAULT_LEADERBOARD_SIZE
+        } else {
+            size_mapper.get()
+        }
+    }
 
-        for i in 1..=patrons.len() {
-            let patron = patrons.get(i);
-            if &patron.donor_address == current_donor {
-                continue;
-            }
-            let other_registry = self.donor_nft_registry_for_entity(&patron.donor_address, entity_name);
-            if other_registry.len() == 0 {
-                continue;
-            }
-            let other_nonce = other_registry.get(other_registry.len());
-            let balance = self.blockchain().get_esdt_balance(&contract_address, nft_token_id, other_nonce);
-            if balance == 0u32 {
-                continue;
-            }
-            let other_donation_count = self.donor_donations_to_entity(&patron.donor_address, entity_name).get();
-            let other_total = self.donor_total_amount_to_entity(&patron.donor_address, entity_name).get();
-            let other_tier = self.calculate_tier_for_entity(other_donation_count);
-            let other_tier_name = self.get_tier_name(other_tier);
-            let pattern_mapper = self.donor_recurring_patterns(&patron.donor_address);
-            let other_pattern = if pattern_mapper.is_empty() {
-                RecurringPattern { monthly_streak: 0, quarterly_streak: 0, last_donation_month: 0, last_donation_quarter: 0 }
-            } else {
-                pattern_mapper.get()
-            };
-            let tier_image_uri = self.get_tier_base_image_uri(other_tier);
+    fn get_donor_patron_rank(&self, donor_address: &ManagedAddress, entity_id: u64) -> Option<u64> {
+        let patrons = self.project_patrons(entity_id);
+        match self.find_patron_index(&patrons, donor_address) {
+            0 => None,
+            index => Some(index as u64),
+        }
+    }
 
-            let attrs = self.create_donation_nft_attributes(
-                entity_name,
-                entity_type,
-                other_donation_count,
-                other_tier,
-                &other_tier_name,
-                &empty_tags,
-                &other_total,
-                Some(patron.patron_rank),
-                Some(&other_pattern),
-                tier_image_uri.clone(),
-            );
-            self.send().nft_update_attributes(nft_token_id, other_nonce, &attrs);
+    /// Rewrites the badges of the patrons the donor overtook, the only ranks a donation changes,
+    /// so e.g. old #1 shows #patron_2. Patrons above or below the move keep their badges untouched.
+    fn update_shifted_patrons_nfts(&self, ctx: &MintContext<Self::Api>, placement: &PatronPlacement<Self::Api>) {
+        let Some(rank) = placement.rank else {
+            return;
+        };
+        let patrons = self.project_patrons(ctx.entity.id);
+        for shifted_rank in (rank + 1)..=placement.shifted_to {
+            let patron = patrons.get(shifted_rank as usize);
+            self.refresh_donor_badge(ctx, &patron.donor_address, Some(shifted_rank));
+        }
+    }
+
+    /// Rewrites a donor's badge attributes for a new patron rank (None once off the leaderboard).
+    fn refresh_donor_badge(&self, ctx: &MintContext<Self::Api>, donor: &ManagedAddress, patron_rank: Option<u64>) {
+        let entity_id = ctx.entity.id;
+        let nft_token_id = &ctx.nft_token_id;
+        let registry = self.donor_nft_registry_for_entity(donor, entity_id);
+        if registry.is_empty() {
+            return;
+        }
+        let nonce = registry.get(registry.len());
+        let contract_address = self.blockchain().get_sc_address();
+        let balance = self.blockchain().get_esdt_balance(&contract_address, nft_token_id, nonce);
+        if balance == 0u32 {
+            return;
+        }
+        let donation_count = self.donor_donations_to_entity(donor, entity_id).get();
+        let total = self.donor_total_amount_to_entity(donor, entity_id).get();
+        let tier_level = self.tier_level_in(&ctx.ladder, donation_count, &total);
+        let tier = self.tier_in(&ctx.ladder, tier_level);
+        let pattern = self.get_recurring_pattern(&self.donor_entity_recurring_patterns(donor, entity_id));
+        let tier_image_uri = self.get_tier_image_uri(&tier);
+        let (last_token, last_amount) = self.get_last_donation(nonce);
+
+        let attrs = self.create_donation_nft_attributes(
+            &ctx.entity.name,
+            &ctx.entity.entity_type,
+            donation_count,
+            &tier,
+            &ManagedVec::new(),
+            &self.donor_token_payments(donor, entity_id),
+            &last_token,
+            &last_amount,
+            patron_rank,
+            Some(&pattern),
+            tier_image_uri,
+        );
+        self.send().nft_update_attributes(nft_token_id, nonce, &attrs);
 
-            let want_type = if patron.patron_rank == 1 { 1u64 } else { 2u64 };
-            let added_type = self.patron_image_type_added(other_nonce).get();
-            let legacy_has_image = added_type == 0 && self.has_patron_uri(other_nonce).get();
+        if let Some(rank) = patron_rank {
+            let want_type = if rank == 1 { 1u64 } else { 2u64 };
+            let added_type = self.patron_image_type_added(nonce).get();
+            let legacy_has_image = added_type == 0 && self.has_patron_uri(nonce).get();
             let should_add = added_type != want_type
                 && (want_type == 1 || !legacy_has_image);
             if should_add {
-                if let Some(patron_uri) = self.get_patron_badge_uri(patron.patron_rank) {
-                    self.send().nft_add_uri(nft_token_id, other_nonce, patron_uri);
-                    self.patron_image_type_added(other_nonce).set(want_type);
-                    self.has_patron_uri(other_nonce).set(true);
+                if let Some(patron_uri) = self.get_patron_badge_uri(rank) {
+                    self.send().nft_add_uri(nft_token_id, nonce, patron_uri);
+                    self.patron_image_type_added(nonce).set(want_type);
+                    self.has_patron_uri(nonce).set(true);
                 }
             }
         }
@@ -1341,30 +2119,29 @@ pub trait PhilanthrifyFactory {
     // RECURRING DONATION TRACKING
     // ============================================================
 
+    /// Advances both the donor's streaks for this entity and their platform-wide streaks; returns the entity's.
     fn update_recurring_patterns(
         &self,
         donor_address: &ManagedAddress,
-        _entity_name: &ManagedBuffer,
+        entity_id: u64,
     ) -> RecurringPattern {
         let current_timestamp = self.blockchain().get_block_timestamp();
-        
-        // Calculate current month (YYYYMM format)
-        // Note: This is simplified - you may want to use a proper date library
-        // For now, we'll use timestamp-based calculation
-        let current_month = self.timestamp_to_month(current_timestamp);
-        let current_quarter = self.timestamp_to_quarter(current_timestamp);
-
-        let pattern_mapper = self.donor_recurring_patterns(donor_address);
-        let mut pattern = if pattern_mapper.is_empty() {
-            RecurringPattern {
-                monthly_streak: 0,
-                quarterly_streak: 0,
-                last_donation_month: 0,
-                last_donation_quarter: 0,
-            }
-        } else {
-            pattern_mapper.get()
-        };
+
+        let global_mapper = self.donor_recurring_patterns(donor_address);
+        let global_pattern = self.advance_recurring_pattern(self.get_recurring_pattern(&global_mapper), current_timestamp);
+        global_mapper.set(&global_pattern);
+
+        let entity_mapper = self.donor_entity_recurring_patterns(donor_address, entity_id);
+        let entity_pattern = self.advance_recurring_pattern(self.get_recurring_pattern(&entity_mapper), current_timestamp);
+        entity_mapper.set(&entity_pattern);
+
+        entity_pattern
+    }
+
+    fn advance_recurring_pattern(&self, mut pattern: RecurringPattern, current_timestamp: u64) -> RecurringPattern {
+        // Calendar month (YYYYMM) and quarter (YYYYQ) of the block, UTC
+        let current_month = calendar::month_of(current_timestamp);
+        let current_quarter = calendar::quarter_of(current_timestamp);
 
         // Check monthly streak
         if pattern.last_donation_month == 0 {
@@ -1372,11 +2149,9 @@ pub trait PhilanthrifyFactory {
             pattern.monthly_streak = 1;
             pattern.quarterly_streak = 1;
         } else {
-            let month_diff = if current_month > pattern.last_donation_month {
-                current_month - pattern.last_donation_month
-            } else {
-                0
-            };
+            // Compare absolute month counts so December -> January is a gap of 1
+            let month_diff = calendar::month_index(current_month)
+                .saturating_sub(calendar::month_index(pattern.last_donation_month));
 
             if month_diff == 1 {
                 // Consecutive month
@@ -1388,11 +2163,8 @@ pub trait PhilanthrifyFactory {
             // If month_diff == 0, same month, keep streak
 
             // Check quarterly streak
-            let quarter_diff = if current_quarter > pattern.last_donation_quarter {
-                current_quarter - pattern.last_donation_quarter
-            } else {
-                0
-            };
+            let quarter_diff = calendar::quarter_index(current_quarter)
+                .saturating_sub(calendar::quarter_index(pattern.last_donation_quarter));
 
             if quarter_diff == 1 {
                 pattern.quarterly_streak += 1;
@@ -1403,42 +2175,37 @@ pub trait PhilanthrifyFactory {
 
         pattern.last_donation_month = current_month;
         pattern.last_donation_quarter = current_quarter;
-
-        self.donor_recurring_patterns(donor_address).set(&pattern);
         pattern
     }
 
-    fn timestamp_to_month(&self, timestamp: u64) -> u64 {
-        // Convert timestamp to YYYYMM format
-        // Unix epoch: Jan 1, 1970
-        // Approximate: 1 month = 2,592,000 seconds (30 days)
-        // This is simplified - for production, use proper date library
-        let seconds_per_month = 2_592_000u64;
-        let months_since_epoch = timestamp / seconds_per_month;
-        // Assuming we start counting from Jan 2024 (month 0)
-        // Adjust base_month based on when your contract was deployed
-        let base_month = 202401u64; // Jan 2024
-        base_month + (months_since_epoch % 12) + ((months_since_epoch / 12) * 100)
-    }
-
-    fn timestamp_to_quarter(&self, timestamp: u64) -> u64 {
-        // Convert timestamp to YYYYQ format (e.g., 20241 for Q1 2024)
-        let seconds_per_month = 2_592_000u64;
-        let months_since_epoch = timestamp / seconds_per_month;
-        let month_in_year = months_since_epoch % 12;
-        let quarter = (month_in_year / 3) + 1;
-        let year = 2024u64 + (months_since_epoch / 12);
-        year * 10 + quarter
+    fn get_recurring_pattern(&self, pattern_mapper: &SingleValueMapper<RecurringPattern>) -> RecurringPattern {
+        if pattern_mapper.is_empty() {
+            RecurringPattern::default()
+        } else {
+            pattern_mapper.get()
+        }
     }
 
     // ============================================================
     // STATISTICS UPDATE
     // ============================================================
 
-    fn update_donation_stats(&self, donation_amount: &BigUint, minted_new_nft: bool) {
+    fn update_donation_stats(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        donation_amount: &BigUint,
+        is_display_only: bool,
+        minted_new_nft: bool,
+    ) {
         let mut stats = self.global_statistics().get();
-        stats.total_donations_amount += donation_amount;
-        stats.total_donations_count += 1;
+        if !is_display_only {
+            // Global amount stays in EGLD; ESDT donations are summed per token
+            if token_identifier.is_egld() {
+                stats.total_donations_amount += donation_amount;
+            }
+            stats.total_donations_count += 1;
+            self.token_donation_total(token_identifier).update(|total| *total += donation_amount);
+        }
         if minted_new_nft {
             stats.total_nfts_minted += 1;
         }
@@ -1482,18 +2249,18 @@ pub trait PhilanthrifyFactory {
     }
 
     #[view(getDonationsByEntity)]
-    fn get_donations_by_entity(&self, entity_name: ManagedBuffer) -> MultiValueEncoded<DonationRecord<Self::Api>> {
+    fn get_donations_by_entity(&self, entity_id: u64) -> MultiValueEncoded<DonationRecord<Self::Api>> {
         let mut result = MultiValueEncoded::new();
-        for item in self.entity_donation_history(&entity_name).iter() {
+        for item in self.entity_donation_history(entity_id).iter() {
             result.push(item);
         }
         result
     }
 
     #[view(getDonorNftsForEntity)]
-    fn get_donor_nfts_for_entity(&self, donor: ManagedAddress, entity_name: ManagedBuffer) -> MultiValueEncoded<u64> {
+    fn get_donor_nfts_for_entity(&self, donor: ManagedAddress, entity_id: u64) -> MultiValueEncoded<u64> {
         let mut result = MultiValueEncoded::new();
-        for item in self.donor_nft_registry_for_entity(&donor, &entity_name).iter() {
+        for item in self.donor_nft_registry_for_entity(&donor, entity_id).iter() {
             result.push(item);
         }
         result
@@ -1504,95 +2271,279 @@ pub trait PhilanthrifyFactory {
         self.nft_nonce().get()
     }
 
+    #[view(getDonorTokenAmountToEntity)]
+    fn get_donor_token_amount_to_entity(
+        &self,
+        donor: ManagedAddress,
+        entity_id: u64,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        self.donor_token_amount_to_entity(&donor, entity_id, &token_identifier).get()
+    }
+
+    #[view(getTokenDonationTotal)]
+    fn get_token_donation_total(&self, token_identifier: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.token_donation_total(&token_identifier).get()
+    }
+
     #[view(getDonationCountToEntity)]
-    fn get_donation_count_to_entity(&self, donor: ManagedAddress, entity_name: ManagedBuffer) -> u64 {
-        self.donor_donations_to_entity(&donor, &entity_name).get()
+    fn get_donation_count_to_entity(&self, donor: ManagedAddress, entity_id: u64) -> u64 {
+        self.donor_donations_to_entity(&donor, entity_id).get()
     }
 
     /// Returns the attributes string that should be on-chain for this donor+entity (for verifying tags/traits update).
     #[view(getDonorNftAttributesPreview)]
-    fn get_donor_nft_attributes_preview(&self, donor: ManagedAddress, entity_name: ManagedBuffer) -> ManagedBuffer {
-        let registry = self.donor_nft_registry_for_entity(&donor, &entity_name);
-        if registry.len() == 0 {
+    fn get_donor_nft_attributes_preview(&self, donor: ManagedAddress, entity_id: u64) -> ManagedBuffer {
+        let registry = self.donor_nft_registry_for_entity(&donor, entity_id);
+        if registry.is_empty() {
             return ManagedBuffer::new();
         }
         let nonce = registry.get(registry.len());
-        let donation_count = self.donor_donations_to_entity(&donor, &entity_name).get();
-        let tier_level = self.calculate_tier_for_entity(donation_count);
-        let tier_name = self.get_tier_name(tier_level);
-        let total_amount = self.donor_total_amount_to_entity(&donor, &entity_name).get();
-        let patron_rank = self.get_donor_patron_rank(&donor, &entity_name);
-        let pattern = self.donor_recurring_patterns(&donor).get();
+        let donation_count = self.donor_donations_to_entity(&donor, entity_id).get();
+        let total_amount = self.donor_total_amount_to_entity(&donor, entity_id).get();
+        let tier_level = self.calculate_tier_for_entity(entity_id, donation_count, &total_amount);
+        let tier = self.get_tier(entity_id, tier_level);
+        let patron_rank = self.get_donor_patron_rank(&donor, entity_id);
+        let pattern = self.get_recurring_pattern(&self.donor_entity_recurring_patterns(&donor, entity_id));
         let recurring_pattern = if pattern.last_donation_month == 0 && pattern.last_donation_quarter == 0 {
             None
         } else {
             Some(&pattern)
         };
-        let entity_type = if self.nft_metadata_record(nonce).is_empty() {
-            ManagedBuffer::from(b"charity")
-        } else {
-            self.nft_metadata_record(nonce).get().entity_type
-        };
+        let metadata_mapper = self.nft_metadata_record(nonce);
+        if metadata_mapper.is_empty() {
+            return ManagedBuffer::new();
+        }
+        let metadata = metadata_mapper.get();
         let user_tags = ManagedVec::new();
-        let tier_image_uri = self.get_tier_base_image_uri(tier_level);
+        let tier_image_uri = self.get_tier_image_uri(&tier);
+        let (last_token, last_amount) = self.get_last_donation(nonce);
         self.create_donation_nft_attributes(
-            &entity_name,
-            &entity_type,
+            &metadata.entity_name,
+            &metadata.entity_type,
             donation_count,
-            tier_level,
-            &tier_name,
+            &tier,
             &user_tags,
-            &total_amount,
+            &self.donor_token_payments(&donor, entity_id),
+            &last_token,
+            &last_amount,
             patron_rank,
             recurring_pattern,
             tier_image_uri,
         )
     }
 
-    #[view(getProjectTemplate)]
-    fn get_project_template(&self) -> ManagedAddress {
-        self.project_template().get()
+    #[view(getEntityIdByName)]
+    fn get_entity_id_by_name(&self, name: ManagedBuffer) -> OptionalValue<u64> {
+        let id_mapper = self.entity_id_by_name(&name);
+        if id_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(id_mapper.get())
+        }
     }
 
-    #[view(getCharityTemplate)]
-    fn get_charity_template(&self) -> ManagedAddress {
-        self.charity_template().get()
+    #[view(isRegisteredEntity)]
+    fn is_registered_entity(&self, address: ManagedAddress) -> bool {
+        !self.registered_entity(&address).is_empty()
     }
 
     // ============================================================
-    // NFT METADATA VIEW FUNCTIONS - Query live NFT data
+    // ENTITY DIRECTORY VIEWS
     // ============================================================
 
-    #[view(getNftMetadata)]
-    fn get_nft_metadata(&self, nft_nonce: u64) -> NftMetadataRecord<Self::Api> {
-        self.nft_metadata_record(nft_nonce).get()
+    #[view(getCharities)]
+    fn get_charities(&self, offset: usize, limit: usize) -> MultiValueEncoded<DirectoryEntry<Self::Api>> {
+        self.directory_page(&self.charity_addresses(), offset, limit)
     }
 
-    #[view(getDonorNftMetadataForEntity)]
-    fn get_donor_nft_metadata_for_entity(
+    #[view(getProjectsOfCharity)]
+    fn get_projects_of_charity(
         &self,
-        donor: ManagedAddress,
-        entity_name: ManagedBuffer,
-    ) -> OptionalValue<NftMetadataRecord<Self::Api>> {
-        let registry = self.donor_nft_registry_for_entity(&donor, &entity_name);
-        let registry_len = registry.len();
-        
-        if registry_len == 0 {
+        charity: ManagedAddress,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<DirectoryEntry<Self::Api>> {
+        self.directory_page(&self.charity_projects(&charity), offset, limit)
+    }
+
+    #[view(getEntityByAddress)]
+    fn get_entity_by_address(&self, address: ManagedAddress) -> OptionalValue<DirectoryEntry<Self::Api>> {
+        if self.registered_entity(&address).is_empty() {
             OptionalValue::None
         } else {
-            let nft_nonce = registry.get(registry_len);
-            let metadata = self.nft_metadata_record(nft_nonce).get();
-            OptionalValue::Some(metadata)
+            OptionalValue::Some(self.directory_entry(address))
         }
     }
 
-    #[view(getTransactionNftForEntity)]
-    fn get_transaction_nft_for_entity(
-        &self,
-        entity_name: ManagedBuffer,
-    ) -> OptionalValue<NftMetadataRecord<Self::Api>> {
-        let nft_nonce_opt = self.entity_transaction_nft(&entity_name).get();
-        
+    #[view(getEntityByName)]
+    fn get_entity_by_name(&self, name: ManagedBuffer) -> OptionalValue<DirectoryEntry<Self::Api>> {
+        let id_mapper = self.entity_id_by_name(&name);
+        if id_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            let address = self.entity_address_by_id(id_mapper.get()).get();
+            OptionalValue::Some(self.directory_entry(address))
+        }
+    }
+
+    #[view(getEntityById)]
+    fn get_entity_by_id(&self, entity_id: u64) -> OptionalValue<DirectoryEntry<Self::Api>> {
+        let address_mapper = self.entity_address_by_id(entity_id);
+        if address_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.directory_entry(address_mapper.get()))
+        }
+    }
+
+    #[view(getCharityCount)]
+    fn get_charity_count(&self) -> u64 {
+        self.charity_count().get()
+    }
+
+    #[view(getProjectCount)]
+    fn get_project_count(&self) -> u64 {
+        self.project_count().get()
+    }
+
+    fn directory_page(
+        &self,
+        addresses: &UnorderedSetMapper<ManagedAddress>,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<DirectoryEntry<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let len = addresses.len();
+        // UnorderedSetMapper indices are 1-based
+        let first = offset.saturating_add(1);
+        let last = core::cmp::min(len, offset.saturating_add(limit));
+        for index in first..=last {
+            result.push(self.directory_entry(addresses.get_by_index(index)));
+        }
+        result
+    }
+
+    fn directory_entry(&self, address: ManagedAddress) -> DirectoryEntry<Self::Api> {
+        let entity = self.registered_entity(&address).get();
+        let parent = if self.project_parent(&address).is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.project_parent(&address).get()
+        };
+        DirectoryEntry {
+            id: entity.id,
+            address,
+            name: entity.name,
+            entity_type: entity.entity_type,
+            parent,
+        }
+    }
+
+    #[view(getProjectTemplate)]
+    fn get_project_template(&self) -> ManagedAddress {
+        self.project_template().get()
+    }
+
+    #[view(getCharityTemplate)]
+    fn get_charity_template(&self) -> ManagedAddress {
+        self.charity_template().get()
+    }
+
+    // ============================================================
+    // NFT METADATA VIEW FUNCTIONS - Query live NFT data
+    // ============================================================
+
+    /// Metadata with is_on_contract read from the factory's live balance, so badges sent back
+    /// by plain transfer are reported correctly too.
+    #[view(getNftMetadata)]
+    fn get_nft_metadata(&self, nft_nonce: u64) -> NftMetadataRecord<Self::Api> {
+        let mut metadata = self.nft_metadata_record(nft_nonce).get();
+        metadata.is_on_contract = self.is_nft_on_contract(nft_nonce);
+        metadata
+    }
+
+    /// Donor badges split into those held by the factory and those withdrawn to a wallet.
+    #[view(getDonorBadgeLocations)]
+    fn get_donor_badge_locations(&self, donor: ManagedAddress) -> MultiValue2<ManagedVec<u64>, ManagedVec<u64>> {
+        let mut held = ManagedVec::new();
+        let mut withdrawn = ManagedVec::new();
+        for nonce in self.donor_badges(&donor).iter() {
+            if self.is_nft_on_contract(nonce) {
+                held.push(nonce);
+            } else {
+                withdrawn.push(nonce);
+            }
+        }
+        (held, withdrawn).into()
+    }
+
+    /// One-off migration for badges minted before the donor_badges index existed: indexes every donor
+    /// badge among `count` nonces starting at `from_nonce`. Run it over 1..=getNftNonce in slices;
+    /// receipts and already indexed badges are skipped. Returns how many badges were added.
+    #[endpoint(backfillDonorBadges)]
+    fn backfill_donor_badges(&self, from_nonce: u64, count: u64) -> u64 {
+        self.only_nft_manager();
+        require!(from_nonce > 0 && count > 0 && count <= MAX_BACKFILL_NONCES, "Count must be 1-500 from nonce 1");
+
+        let last_nonce = core::cmp::min(from_nonce + count - 1, self.nft_nonce().get());
+        let mut added = 0u64;
+        for nonce in from_nonce..=last_nonce {
+            let metadata_mapper = self.nft_metadata_record(nonce);
+            if metadata_mapper.is_empty() {
+                continue;
+            }
+            let metadata = metadata_mapper.get();
+            let registry = self.donor_nft_registry_for_entity(&metadata.donor_address, metadata.entity_id);
+            if registry.iter().any(|badge_nonce| badge_nonce == nonce)
+                && self.donor_badges(&metadata.donor_address).insert(nonce)
+            {
+                added += 1;
+            }
+        }
+        self.donor_badges_backfilled(from_nonce, last_nonce, added);
+        added
+    }
+
+    fn is_nft_on_contract(&self, nft_nonce: u64) -> bool {
+        let nft_token_id = self.global_nft_collection().get();
+        if !nft_token_id.is_valid_esdt_identifier() {
+            return false;
+        }
+        self.blockchain().get_esdt_balance(&self.blockchain().get_sc_address(), &nft_token_id, nft_nonce) > 0u32
+    }
+
+    fn set_nft_on_contract(&self, nft_nonce: u64, is_on_contract: bool) {
+        let metadata_mapper = self.nft_metadata_record(nft_nonce);
+        if !metadata_mapper.is_empty() {
+            metadata_mapper.update(|metadata| metadata.is_on_contract = is_on_contract);
+        }
+    }
+
+    #[view(getDonorNftMetadataForEntity)]
+    fn get_donor_nft_metadata_for_entity(
+        &self,
+        donor: ManagedAddress,
+        entity_id: u64,
+    ) -> OptionalValue<NftMetadataRecord<Self::Api>> {
+        let registry = self.donor_nft_registry_for_entity(&donor, entity_id);
+        let registry_len = registry.len();
+        
+        if registry_len == 0 {
+            OptionalValue::None
+        } else {
+            let nft_nonce = registry.get(registry_len);
+            OptionalValue::Some(self.get_nft_metadata(nft_nonce))
+        }
+    }
+
+    #[view(getTransactionNftForEntity)]
+    fn get_transaction_nft_for_entity(
+        &self,
+        entity_id: u64,
+    ) -> OptionalValue<NftMetadataRecord<Self::Api>> {
+        let nft_nonce_opt = self.entity_transaction_nft(entity_id).get();
+        
         if nft_nonce_opt == 0 {
             OptionalValue::None
         } else {
@@ -1601,16 +2552,168 @@ pub trait PhilanthrifyFactory {
         }
     }
 
+    // ============================================================
+    // SPENDING LEDGER
+    // ============================================================
+
+    /// Receipts per transaction instead of one aggregated receipt; applies to transactions recorded afterwards.
+    #[endpoint(setEntityReceiptMode)]
+    fn set_entity_receipt_mode(&self, entity_id: u64, mode: ReceiptMode) {
+        self.only_nft_manager();
+        require!(!self.entity_address_by_id(entity_id).is_empty(), "Entity not found");
+        self.entity_receipt_mode(entity_id).set(mode);
+    }
+
+    #[view(getEntityReceiptMode)]
+    fn get_entity_receipt_mode(&self, entity_id: u64) -> ReceiptMode {
+        self.entity_receipt_mode(entity_id).get()
+    }
+
+    #[view(getSpendingCount)]
+    fn get_spending_count(&self, entity_id: u64) -> usize {
+        self.spending_records(entity_id).len()
+    }
+
+    /// Spending the entity declared without paying it from its treasury (display-only, in EGLD).
+    #[view(getEntityDisplaySpending)]
+    fn get_entity_display_spending(&self, entity_id: u64) -> BigUint {
+        self.entity_transaction_total(entity_id).get()
+    }
+
+    /// Spending paid out of the entity's treasury, per token; matches the entity's getTotalSpent.
+    #[view(getEntityPaidSpending)]
+    fn get_entity_paid_spending(&self, entity_id: u64) -> MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.entity_spent_tokens(entity_id).iter() {
+            let amount = self.entity_spent_by_token(entity_id, &token).get();
+            result.push(EgldOrEsdtTokenPayment::new(token, 0, amount));
+        }
+        result
+    }
+
+    /// Spending records in the order they were recorded.
+    #[view(getSpendingRecords)]
+    fn get_spending_records(
+        &self,
+        entity_id: u64,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<SpendingRecord<Self::Api>> {
+        let records = self.spending_records(entity_id);
+        let mut result = MultiValueEncoded::new();
+        let first = offset.saturating_add(1);
+        let last = core::cmp::min(records.len(), offset.saturating_add(limit));
+        for index in first..=last {
+            result.push(records.get(index));
+        }
+        result
+    }
+
+    #[view(getSpendingByCategory)]
+    fn get_spending_by_category(
+        &self,
+        entity_id: u64,
+        category: ManagedBuffer,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<SpendingRecord<Self::Api>> {
+        let records = self.spending_records(entity_id);
+        let ids = self.spending_ids_by_category(entity_id, &category);
+        let mut result = MultiValueEncoded::new();
+        let first = offset.saturating_add(1);
+        let last = core::cmp::min(ids.len(), offset.saturating_add(limit));
+        for index in first..=last {
+            result.push(records.get(ids.get(index) as usize));
+        }
+        result
+    }
+
+    /// Marks a spending record for review; flagging it again replaces the reason.
+    #[endpoint(flagSpendingRecord)]
+    fn flag_spending_record(&self, entity_id: u64, record_id: u64, reason: ManagedBuffer) {
+        self.only_auditor();
+        require!(
+            record_id >= 1 && record_id as usize <= self.spending_records(entity_id).len(),
+            "Spending record not found"
+        );
+        require!(!reason.is_empty(), "Reason required");
+        let auditor = self.blockchain().get_caller();
+        self.spending_flag(entity_id, record_id).set(SpendingFlag {
+            record_id,
+            auditor: auditor.clone(),
+            reason: reason.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+        self.flagged_spending_ids(entity_id).insert(record_id);
+        self.spending_record_flagged(entity_id, record_id, &auditor, &reason);
+    }
+
+    #[endpoint(clearSpendingFlag)]
+    fn clear_spending_flag(&self, entity_id: u64, record_id: u64) {
+        self.only_auditor();
+        require!(self.flagged_spending_ids(entity_id).swap_remove(&record_id), "Spending record not flagged");
+        self.spending_flag(entity_id, record_id).clear();
+        self.spending_flag_cleared(entity_id, record_id, &self.blockchain().get_caller());
+    }
+
+    /// Open auditor flags on an entity's spending ledger, in no particular order.
+    #[view(getSpendingFlags)]
+    fn get_spending_flags(&self, entity_id: u64) -> MultiValueEncoded<SpendingFlag<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for record_id in self.flagged_spending_ids(entity_id).iter() {
+            result.push(self.spending_flag(entity_id, record_id).get());
+        }
+        result
+    }
+
+    /// Spending records with `start <= timestamp <= end`, skipping the first `offset` of them.
+    #[view(getSpendingInTimeRange)]
+    fn get_spending_in_time_range(
+        &self,
+        entity_id: u64,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<SpendingRecord<Self::Api>> {
+        let records = self.spending_records(entity_id);
+        // Records are appended in block order, so timestamps never decrease; find the first one in range
+        let (mut low, mut high) = (1usize, records.len() + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if records.get(middle).timestamp < start_timestamp {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        let mut result = MultiValueEncoded::new();
+        let mut index = low.saturating_add(offset);
+        while index <= records.len() && result.len() < limit {
+            let record = records.get(index);
+            if record.timestamp > end_timestamp {
+                break;
+            }
+            result.push(record);
+            index += 1;
+        }
+        result
+    }
+
     // ============================================================
     // NFT RETRIEVAL - Allow donor to get their NFT from contract
     // ============================================================
     #[endpoint(retrieveDonorNft)]
-    fn retrieve_donor_nft(&self, entity_name: ManagedBuffer) {
+    fn retrieve_donor_nft(&self, entity_id: u64) {
         let caller = self.blockchain().get_caller();
         let nft_token_id = self.global_nft_collection().get();
         require!(nft_token_id.is_valid_esdt_identifier(), "NFT collection not set");
 
-        let registry = self.donor_nft_registry_for_entity(&caller, &entity_name);
+        let custody = self.badge_custody_for_entity(entity_id);
+        require!(custody != BadgeCustody::Soulbound, "Badges for this entity are soulbound");
+
+        let registry = self.donor_nft_registry_for_entity(&caller, entity_id);
         let registry_len = registry.len();
         
         require!(registry_len > 0, "No NFT found for this entity");
@@ -1634,6 +2737,250 @@ pub trait PhilanthrifyFactory {
             nft_nonce,
             &BigUint::from(1u32),
         );
+        self.set_nft_on_contract(nft_nonce, false);
+        self.donor_nft_retrieved(&caller, entity_id, nft_nonce);
+
+        if custody == BadgeCustody::RetrievableFrozen {
+            self.send()
+                .esdt_system_sc_proxy()
+                .freeze_nft(&nft_token_id, nft_nonce, &caller)
+                .with_callback(self.callbacks().badge_freeze_callback(caller, nft_nonce, true))
+                .async_call_and_exit();
+        }
+    }
+
+    /// Records whether a freeze or unfreeze of a retrieved badge went through. The badge has already left
+    /// the factory either way, so a failed freeze leaves it transferable and only emits badge_freeze_failed.
+    #[callback]
+    fn badge_freeze_callback(
+        &self,
+        donor: ManagedAddress,
+        nft_nonce: u64,
+        freeze: bool,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.badge_frozen(nft_nonce).set(freeze);
+                self.badge_freeze_changed(&donor, nft_nonce, freeze);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                self.badge_freeze_failed(&donor, nft_nonce, freeze);
+            },
+        }
+    }
+
+    /// Sends the caller's on-contract badges in one multi-transfer, optionally only for the given entities.
+    /// Soulbound and frozen-custody badges are skipped; frozen ones go through retrieveDonorNft.
+    #[endpoint(retrieveAllDonorNfts)]
+    fn retrieve_all_donor_nfts(&self, max_count: usize, entity_ids: MultiValueEncoded<u64>) {
+        require!(
+            max_count > 0 && max_count <= MAX_BADGES_PER_RETRIEVAL,
+            "Max count must be 1-100"
+        );
+        let caller = self.blockchain().get_caller();
+        let nft_token_id = self.global_nft_collection().get();
+        require!(nft_token_id.is_valid_esdt_identifier(), "NFT collection not set");
+
+        let entity_filter = entity_ids.to_vec();
+        let mut payments = ManagedVec::new();
+        let mut nonces = ManagedVec::new();
+        for nonce in self.donor_badges(&caller).iter() {
+            if nonces.len() == max_count {
+                break;
+            }
+            let metadata = self.nft_metadata_record(nonce).get();
+            if !entity_filter.is_empty() && !entity_filter.contains(&metadata.entity_id) {
+                continue;
+            }
+            if self.badge_custody_for_entity(metadata.entity_id) != BadgeCustody::Transferable {
+                continue;
+            }
+            if !self.is_nft_on_contract(nonce) {
+                continue;
+            }
+            payments.push(EsdtTokenPayment::new(nft_token_id.clone(), nonce, BigUint::from(1u32)));
+            nonces.push(nonce);
+        }
+        require!(!nonces.is_empty(), "No badges to retrieve");
+
+        self.send().direct_multi(&caller, &payments);
+        for nonce in nonces.iter() {
+            self.set_nft_on_contract(nonce, false);
+        }
+        self.donor_nfts_retrieved(&caller, &nonces);
+    }
+
+    /// Deposit a retrieved badge back on the factory so it resumes dynamic updates.
+    /// Frozen badges need unfreezeDonorNft first.
+    #[payable("*")]
+    #[endpoint(returnDonorNft)]
+    fn return_donor_nft(&self) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_esdt();
+        let nft_token_id = self.global_nft_collection().get();
+        require!(payment.token_identifier == nft_token_id, "Not a Philanthrify badge");
+        require!(payment.amount == 1u32, "Send exactly one badge");
+
+        let metadata_mapper = self.nft_metadata_record(payment.token_nonce);
+        require!(!metadata_mapper.is_empty(), "Not a donor badge");
+        let metadata = metadata_mapper.get();
+        require!(metadata.donor_address == caller, "Only the donor can return this badge");
+        self.set_nft_on_contract(payment.token_nonce, true);
+        self.donor_badges(&caller).insert(payment.token_nonce);
+
+        // Bring attributes up to date with donations made while the badge was away
+        let patron_rank = self.get_donor_patron_rank(&caller, metadata.entity_id);
+        let entity = EntityInfo {
+            id: metadata.entity_id,
+            name: metadata.entity_name,
+            entity_type: metadata.entity_type,
+        };
+        let ctx = MintContext { entity, nft_token_id, ladder: self.load_tier_ladder(metadata.entity_id) };
+        self.refresh_donor_badge(&ctx, &caller, patron_rank);
+        self.donor_nft_returned(&caller, metadata.entity_id, payment.token_nonce);
+    }
+
+    /// Lets a donor holding a frozen badge send it back with returnDonorNft.
+    #[endpoint(unfreezeDonorNft)]
+    fn unfreeze_donor_nft(&self, donor: ManagedAddress, nft_nonce: u64) {
+        self.only_nft_manager();
+        let nft_token_id = self.global_nft_collection().get();
+        require!(nft_token_id.is_valid_esdt_identifier(), "NFT collection not set");
+        let metadata_mapper = self.nft_metadata_record(nft_nonce);
+        require!(
+            !metadata_mapper.is_empty() && metadata_mapper.get().donor_address == donor,
+            "Badge does not belong to donor"
+        );
+
+        self.send()
+            .esdt_system_sc_proxy()
+            .unfreeze_nft(&nft_token_id, nft_nonce, &donor)
+            .with_callback(self.callbacks().badge_freeze_callback(donor, nft_nonce, false))
+            .async_call_and_exit();
+    }
+
+    // ============================================================
+    // RANKING UNIT
+    // ============================================================
+
+    /// Tiers and the patron leaderboard rank donors in EGLD. EGLD and display-only amounts (declared in EGLD)
+    /// count as they are; an ESDT counts at its ranking rate, or not at all when none is set.
+    fn ranking_value(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) -> BigUint {
+        if token.is_egld() {
+            return amount.clone();
+        }
+        match self.token_ranking_rate(token).get() {
+            Some(rate) => amount * &rate / BigUint::from(10u32).pow(self.get_token_decimals(token.clone())),
+            None => BigUint::zero(),
+        }
+    }
+
+    /// EGLD (in its smallest unit) that one whole token counts as towards tiers and the leaderboard.
+    /// Applies to donations made afterwards; set the token's decimals first.
+    #[endpoint(setTokenRankingRate)]
+    fn set_token_ranking_rate(&self, token: TokenIdentifier, egld_per_token: BigUint) {
+        self.only_nft_manager();
+        let token = EgldOrEsdtTokenIdentifier::esdt(token);
+        require!(!self.token_decimals(&token).is_empty(), "Token decimals not set");
+        self.token_ranking_rate(&token).set(Some(egld_per_token));
+    }
+
+    #[endpoint(clearTokenRankingRate)]
+    fn clear_token_ranking_rate(&self, token: TokenIdentifier) {
+        self.only_nft_manager();
+        self.token_ranking_rate(&EgldOrEsdtTokenIdentifier::esdt(token)).clear();
+    }
+
+    #[view(getTokenRankingRate)]
+    fn get_token_ranking_rate(&self, token: TokenIdentifier) -> OptionalValue<BigUint> {
+        self.token_ranking_rate(&EgldOrEsdtTokenIdentifier::esdt(token)).get().into()
+    }
+
+    /// Everything the donor gave the entity, per token: (token, total).
+    #[view(getDonorTokenTotals)]
+    fn get_donor_token_totals(
+        &self,
+        donor: ManagedAddress,
+        entity_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.donor_tokens_to_entity(&donor, entity_id).iter() {
+            let total = self.donor_token_amount_to_entity(&donor, entity_id, &token).get();
+            result.push((token, total).into());
+        }
+        result
+    }
+
+    // ============================================================
+    // AMOUNT FORMAT
+    // ============================================================
+
+    /// Decimals and shown precision for totals and receipt amounts (default 18 and 4).
+    #[endpoint(setAmountFormat)]
+    fn set_amount_format(&self, decimals: u32, precision: u32) {
+        self.only_nft_manager();
+        require!(decimals <= MAX_TOKEN_DECIMALS && precision <= MAX_TOKEN_DECIMALS, "Decimals must be 0-18");
+        self.amount_format().set(AmountFormat { decimals, precision });
+    }
+
+    #[endpoint(setTokenDecimals)]
+    fn set_token_decimals(&self, token: EgldOrEsdtTokenIdentifier, decimals: u32) {
+        self.only_nft_manager();
+        require!(decimals <= MAX_TOKEN_DECIMALS, "Decimals must be 0-18");
+        self.token_decimals(&token).set(Some(decimals));
+    }
+
+    #[view(getAmountFormat)]
+    fn get_amount_format(&self) -> AmountFormat {
+        self.amount_format_or_default()
+    }
+
+    #[view(getTokenDecimals)]
+    fn get_token_decimals(&self, token: EgldOrEsdtTokenIdentifier) -> u32 {
+        self.token_decimals(&token).get().unwrap_or(self.amount_format_or_default().decimals)
+    }
+
+    // ============================================================
+    // BADGE CUSTODY POLICY
+    // ============================================================
+
+    fn badge_custody_for_entity(&self, entity_id: u64) -> BadgeCustody {
+        if let Some(custody) = self.entity_badge_custody(entity_id).get() {
+            return custody;
+        }
+        let platform_custody = self.badge_custody();
+        if platform_custody.is_empty() {
+            BadgeCustody::Transferable
+        } else {
+            platform_custody.get()
+        }
+    }
+
+    /// Platform-wide badge custody, used by entities without their own.
+    #[endpoint(setBadgeCustody)]
+    fn set_badge_custody(&self, custody: BadgeCustody) {
+        self.only_nft_manager();
+        self.badge_custody().set(custody);
+    }
+
+    #[endpoint(setEntityBadgeCustody)]
+    fn set_entity_badge_custody(&self, entity_id: u64, custody: BadgeCustody) {
+        self.only_nft_manager();
+        require!(!self.entity_address_by_id(entity_id).is_empty(), "Entity not found");
+        self.entity_badge_custody(entity_id).set(Some(custody));
+    }
+
+    #[endpoint(clearEntityBadgeCustody)]
+    fn clear_entity_badge_custody(&self, entity_id: u64) {
+        self.only_nft_manager();
+        self.entity_badge_custody(entity_id).clear();
+    }
+
+    /// Badge custody in effect for an entity (its own, the platform-wide one, or Transferable by default).
+    #[view(getEntityBadgeCustody)]
+    fn get_entity_badge_custody(&self, entity_id: u64) -> BadgeCustody {
+        self.badge_custody_for_entity(entity_id)
     }
 
     // ============================================================
@@ -1643,18 +2990,90 @@ pub trait PhilanthrifyFactory {
     #[event("nft_minted")]
     fn nft_minted(&self, #[indexed] donor: &ManagedAddress, #[indexed] entity: &ManagedBuffer, #[indexed] nonce: u64);
 
+    #[event("donor_nft_retrieved")]
+    fn donor_nft_retrieved(&self, #[indexed] donor: &ManagedAddress, #[indexed] entity_id: u64, #[indexed] nonce: u64);
+
+    #[event("donor_nfts_retrieved")]
+    fn donor_nfts_retrieved(&self, #[indexed] donor: &ManagedAddress, nonces: &ManagedVec<u64>);
+
+    #[event("spending_record_flagged")]
+    fn spending_record_flagged(
+        &self,
+        #[indexed] entity_id: u64,
+        #[indexed] record_id: u64,
+        #[indexed] auditor: &ManagedAddress,
+        reason: &ManagedBuffer,
+    );
+
+    #[event("spending_flag_cleared")]
+    fn spending_flag_cleared(&self, #[indexed] entity_id: u64, #[indexed] record_id: u64, #[indexed] auditor: &ManagedAddress);
+
+    #[event("badge_freeze_changed")]
+    fn badge_freeze_changed(&self, #[indexed] donor: &ManagedAddress, #[indexed] nonce: u64, #[indexed] frozen: bool);
+
+    #[event("badge_freeze_failed")]
+    fn badge_freeze_failed(&self, #[indexed] donor: &ManagedAddress, #[indexed] nonce: u64, #[indexed] freeze: bool);
+
+    #[event("donor_badges_backfilled")]
+    fn donor_badges_backfilled(&self, #[indexed] from_nonce: u64, #[indexed] to_nonce: u64, #[indexed] added: u64);
+
+    #[event("donor_nft_returned")]
+    fn donor_nft_returned(&self, #[indexed] donor: &ManagedAddress, #[indexed] entity_id: u64, #[indexed] nonce: u64);
+
     #[event("transaction_nft_minted")]
     fn transaction_nft_minted(&self, #[indexed] entity: &ManagedBuffer, #[indexed] entity_type: &ManagedBuffer, #[indexed] nonce: u64);
 
+    #[event("transaction_batch_recorded")]
+    fn transaction_batch_recorded(
+        &self,
+        #[indexed] entity: &ManagedBuffer,
+        #[indexed] nonce: u64,
+        #[indexed] transaction_count: usize,
+        total_amount: &BigUint,
+    );
+
     #[event("donation_recorded")]
     fn donation_recorded(&self, #[indexed] donor: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] entity: &ManagedBuffer);
 
+    #[event("donation_batch_recorded")]
+    fn donation_batch_recorded(&self, #[indexed] entity: &ManagedBuffer, #[indexed] donation_count: usize, total_amount: &BigUint);
+
     #[event("nft_attributes_updated")]
     fn nft_attributes_updated(&self, #[indexed] donor: &ManagedAddress, #[indexed] entity: &ManagedBuffer, #[indexed] nonce: u64);
 
     #[event("charity_deployed")]
     fn charity_deployed(&self, #[indexed] name: &ManagedBuffer, #[indexed] address: &ManagedAddress);
 
+    #[event("entity_registered")]
+    fn entity_registered(&self, #[indexed] address: &ManagedAddress, #[indexed] name: &ManagedBuffer, #[indexed] entity_type: &ManagedBuffer);
+
+    #[event("project_deployed")]
+    fn project_deployed(&self, #[indexed] name: &ManagedBuffer, #[indexed] address: &ManagedAddress, #[indexed] charity: &ManagedAddress);
+
+    #[event("entity_unregistered")]
+    fn entity_unregistered(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("unpaused")]
+    fn unpaused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("ownership_transfer_started")]
+    fn ownership_transfer_started(&self, #[indexed] current: &ManagedAddress, #[indexed] pending: &ManagedAddress);
+
+    #[event("ownership_transfer_cancelled")]
+    fn ownership_transfer_cancelled(&self, #[indexed] pending: &ManagedAddress);
+
+    #[event("ownership_transferred")]
+    fn ownership_transferred(&self, #[indexed] previous: &ManagedAddress, #[indexed] new: &ManagedAddress);
+
+    #[event("role_granted")]
+    fn role_granted(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
     #[event("nft_collection_issued")]
     fn nft_collection_issued(&self, #[indexed] token_identifier: &TokenIdentifier);
 
@@ -1664,21 +3083,144 @@ pub trait PhilanthrifyFactory {
 
     #[endpoint(setTierImageUri)]
     fn set_tier_image_uri(&self, tier: u64, uri: ManagedBuffer) {
-        self.only_owner();
+        self.only_nft_manager();
         require!(!uri.is_empty(), "URI cannot be empty");
-        
-        match tier {
-            1 => self.tier_bronze_image_uri().set(&uri),
-            2 => self.tier_silver_image_uri().set(&uri),
-            3 => self.tier_gold_image_uri().set(&uri),
-            4 => self.tier_platinum_image_uri().set(&uri),
-            _ => sc_panic!("Invalid tier number (1-4)"),
+        let mut tiers = self.tiers();
+        require!(tier >= 1 && tier as usize <= tiers.len(), "Invalid tier number");
+
+        let mut tier_config = tiers.get(tier as usize);
+        tier_config.image_uri = uri;
+        tiers.set(tier as usize, &tier_config);
+    }
+
+    // ============================================================
+    // TIER TABLE ENDPOINTS
+    // ============================================================
+
+    #[endpoint(addTier)]
+    fn add_tier(
+        &self,
+        name: ManagedBuffer,
+        tag: ManagedBuffer,
+        image_uri: ManagedBuffer,
+        min_donations: u64,
+        min_amount: BigUint,
+    ) {
+        self.only_nft_manager();
+        let tier = TierConfig { name, tag, image_uri, min_donations, min_amount };
+        self.require_valid_tier(&tier);
+
+        let mut tiers = self.tiers();
+        tiers.push(&tier);
+        self.require_tier_order(&tiers, tiers.len());
+    }
+
+    #[endpoint(updateTier)]
+    fn update_tier(
+        &self,
+        tier_level: u64,
+        name: ManagedBuffer,
+        tag: ManagedBuffer,
+        image_uri: ManagedBuffer,
+        min_donations: u64,
+        min_amount: BigUint,
+    ) {
+        self.only_nft_manager();
+        let tier = TierConfig { name, tag, image_uri, min_donations, min_amount };
+        self.require_valid_tier(&tier);
+
+        let mut tiers = self.tiers();
+        require!(tier_level >= 1 && tier_level as usize <= tiers.len(), "Invalid tier number");
+        tiers.set(tier_level as usize, &tier);
+        self.require_tier_order(&tiers, tier_level as usize);
+    }
+
+    #[endpoint(removeLastTier)]
+    fn remove_last_tier(&self) {
+        self.only_nft_manager();
+        let mut tiers = self.tiers();
+        require!(tiers.len() > 1, "At least one tier is required");
+        tiers.swap_remove(tiers.len());
+    }
+
+    /// Replaces the tier table of one entity; the entity then ignores the platform-wide table.
+    #[endpoint(setEntityTiers)]
+    fn set_entity_tiers(
+        &self,
+        entity_id: u64,
+        tiers: MultiValueEncoded<MultiValue5<ManagedBuffer, ManagedBuffer, ManagedBuffer, u64, BigUint>>,
+    ) {
+        self.only_nft_manager();
+        require!(!self.entity_address_by_id(entity_id).is_empty(), "Entity not found");
+
+        let mut entity_tiers = self.entity_tiers(entity_id);
+        entity_tiers.clear();
+        for tier in tiers.into_iter() {
+            let (name, tag, image_uri, min_donations, min_amount) = tier.into_tuple();
+            let tier = TierConfig { name, tag, image_uri, min_donations, min_amount };
+            self.require_valid_tier(&tier);
+            entity_tiers.push(&tier);
+            self.require_tier_order(&entity_tiers, entity_tiers.len());
+        }
+        require!(!entity_tiers.is_empty(), "At least one tier is required");
+    }
+
+    #[endpoint(clearEntityTiers)]
+    fn clear_entity_tiers(&self, entity_id: u64) {
+        self.only_nft_manager();
+        self.entity_tiers(entity_id).clear();
+    }
+
+    /// Platform-wide tier policy, used by entities without their own.
+    #[endpoint(setTierPolicy)]
+    fn set_tier_policy(&self, policy: TierPolicy) {
+        self.only_nft_manager();
+        self.require_valid_tier_policy(&policy);
+        self.tier_policy().set(policy);
+    }
+
+    #[endpoint(setEntityTierPolicy)]
+    fn set_entity_tier_policy(&self, entity_id: u64, policy: TierPolicy) {
+        self.only_nft_manager();
+        require!(!self.entity_address_by_id(entity_id).is_empty(), "Entity not found");
+        self.require_valid_tier_policy(&policy);
+        self.entity_tier_policy(entity_id).set(policy);
+    }
+
+    #[endpoint(clearEntityTierPolicy)]
+    fn clear_entity_tier_policy(&self, entity_id: u64) {
+        self.only_nft_manager();
+        self.entity_tier_policy(entity_id).clear();
+    }
+
+    /// Tier policy in effect for an entity (its own, the platform-wide one, or DonationCount by default).
+    #[view(getEntityTierPolicy)]
+    fn get_entity_tier_policy(&self, entity_id: u64) -> TierPolicy {
+        self.tier_policy_for_entity(entity_id)
+    }
+
+    #[view(getTiers)]
+    fn get_tiers(&self) -> MultiValueEncoded<TierConfig<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for tier in self.tiers().iter() {
+            result.push(tier);
         }
+        result
+    }
+
+    /// Tier table in effect for an entity (its override, or the platform-wide table).
+    #[view(getEntityTiers)]
+    fn get_entity_tiers(&self, entity_id: u64) -> MultiValueEncoded<TierConfig<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for tier in self.tier_table(entity_id).iter() {
+            result.push(tier);
+        }
+        result
     }
 
     #[endpoint(setPatronImageUri)]
     fn set_patron_image_uri(&self, patron_type: u64, uri: ManagedBuffer) {
-        self.only_owner();
+        self.only_nft_manager();
         require!(!uri.is_empty(), "URI cannot be empty");
         
         match patron_type {
@@ -1689,48 +3231,133 @@ pub trait PhilanthrifyFactory {
     }
 
     #[view(getProjectPatrons)]
-    fn get_project_patrons(&self, project_name: ManagedBuffer) -> MultiValueEncoded<PatronRecord<Self::Api>> {
+    fn get_project_patrons(&self, entity_id: u64) -> MultiValueEncoded<PatronRecord<Self::Api>> {
         let mut result = MultiValueEncoded::new();
-        for item in self.project_patrons(&project_name).iter() {
+        for item in self.project_patrons(entity_id).iter() {
             result.push(item);
         }
         result
     }
 
     #[view(getDonorPatronRank)]
-    fn get_donor_patron_rank_view(&self, donor: ManagedAddress, project_name: ManagedBuffer) -> OptionalValue<u64> {
-        let patrons = self.project_patrons(&project_name);
-        for i in 1..=patrons.len() {
-            let patron = patrons.get(i);
-            if patron.donor_address == donor {
-                return OptionalValue::Some(patron.patron_rank);
+    fn get_donor_patron_rank_view(&self, donor: ManagedAddress, entity_id: u64) -> OptionalValue<u64> {
+        self.get_donor_patron_rank(&donor, entity_id).into()
+    }
+
+    /// Rank among all donors of the entity, including those below the leaderboard. Ranking below the board
+    /// iterates every donor, so it is only answered for entities with up to 1000 donors (None beyond that).
+    #[view(getDonorLeaderboardRank)]
+    fn get_donor_leaderboard_rank(&self, donor: ManagedAddress, entity_id: u64) -> OptionalValue<u64> {
+        let donors = self.entity_donors(entity_id);
+        if !donors.contains(&donor) {
+            return OptionalValue::None;
+        }
+        if let Some(rank) = self.get_donor_patron_rank(&donor, entity_id) {
+            return OptionalValue::Some(rank);
+        }
+        if donors.len() > MAX_RANK_SCAN_DONORS {
+            return OptionalValue::None;
+        }
+
+        let record = self.donor_leaderboard_record(&donor, entity_id);
+        let mut rank = 1u64;
+        for other in donors.iter() {
+            if other != donor && self.ranks_above(&self.donor_leaderboard_record(&other, entity_id), &record) {
+                rank += 1;
             }
         }
-        OptionalValue::None
+        OptionalValue::Some(rank)
     }
 
-    #[view(getDonorRecurringPattern)]
-    fn get_donor_recurring_pattern(&self, donor: ManagedAddress) -> RecurringPattern {
-        let pattern_mapper = self.donor_recurring_patterns(&donor);
-        if pattern_mapper.is_empty() {
-            RecurringPattern {
-                monthly_streak: 0,
-                quarterly_streak: 0,
-                last_donation_month: 0,
-                last_donation_quarter: 0,
+    #[view(getLeaderboardSize)]
+    fn get_leaderboard_size_view(&self, entity_id: u64) -> usize {
+        self.get_leaderboard_size(entity_id)
+    }
+
+    fn donor_leaderboard_record(&self, donor: &ManagedAddress, entity_id: u64) -> PatronRecord<Self::Api> {
+        PatronRecord {
+            donor_address: donor.clone(),
+            total_amount: self.donor_total_amount_to_entity(donor, entity_id).get(),
+            patron_rank: 0,
+            since_timestamp: self.donor_first_donation_timestamp(donor, entity_id).get(),
+        }
+    }
+
+    /// Shrinking drops the lowest entries; growing re-admits the best donors below the board from their totals.
+    /// Growing walks every donor of the entity, so it is rejected above 1000 donors.
+    #[endpoint(setLeaderboardSize)]
+    fn set_leaderboard_size(&self, entity_id: u64, size: usize) {
+        self.only_nft_manager();
+        let address_mapper = self.entity_address_by_id(entity_id);
+        require!(!address_mapper.is_empty(), "Entity not found");
+        require!(size > 0 && size <= MAX_LEADERBOARD_SIZE, "Leaderboard size must be 1-100");
+
+        self.entity_leaderboard_size(entity_id).set(size);
+        let entity = self.registered_entity(&address_mapper.get()).get();
+        let nft_token_id = self.global_nft_collection().get();
+        let ctx = MintContext { entity, nft_token_id, ladder: self.load_tier_ladder(entity_id) };
+        let mut patrons = self.project_patrons(entity_id);
+        while patrons.len() > size {
+            let dropped = patrons.get(patrons.len());
+            patrons.swap_remove(patrons.len());
+            if ctx.nft_token_id.is_valid_esdt_identifier() {
+                self.refresh_donor_badge(&ctx, &dropped.donor_address, None);
+            }
+        }
+
+        // Donors below the board all rank under its last entry, so re-admitted donors only append
+        let previous_len = patrons.len();
+        if previous_len >= size {
+            return;
+        }
+        let donors = self.entity_donors(entity_id);
+        require!(donors.len() <= MAX_RANK_SCAN_DONORS, "Too many donors to grow the leaderboard");
+        for donor in donors.iter() {
+            if self.find_patron_index(&patrons, &donor) == 0 {
+                let total = self.donor_total_amount_to_entity(&donor, entity_id).get();
+                self.check_and_update_patrons(&donor, entity_id, &total);
+            }
+        }
+        if ctx.nft_token_id.is_valid_esdt_identifier() {
+            for index in (previous_len + 1)..=patrons.len() {
+                let admitted = patrons.get(index);
+                self.refresh_donor_badge(&ctx, &admitted.donor_address, Some(index as u64));
             }
-        } else {
-            pattern_mapper.get()
         }
     }
 
+    /// Streaks across all of the donor's donations, whatever the entity.
+    #[view(getDonorRecurringPattern)]
+    fn get_donor_recurring_pattern(&self, donor: ManagedAddress) -> RecurringPattern {
+        self.get_recurring_pattern(&self.donor_recurring_patterns(&donor))
+    }
+
+    /// Streaks of the donor's donations to one entity, as shown on that entity's badge.
+    #[view(getDonorEntityRecurringPattern)]
+    fn get_donor_entity_recurring_pattern(&self, donor: ManagedAddress, entity_id: u64) -> RecurringPattern {
+        self.get_recurring_pattern(&self.donor_entity_recurring_patterns(&donor, entity_id))
+    }
+
     // ============================================================
     // STORAGE
     // ============================================================
 
+    #[view(getGlobalAdmin)]
     #[storage_mapper("global_admin_address")]
     fn global_admin_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getPendingGlobalAdmin)]
+    #[storage_mapper("pending_global_admin")]
+    fn pending_global_admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
     #[storage_mapper("charity_template")]
     fn charity_template(&self) -> SingleValueMapper<ManagedAddress>;
 
@@ -1749,20 +3376,66 @@ pub trait PhilanthrifyFactory {
     #[storage_mapper("nft_nonce")]
     fn nft_nonce(&self) -> SingleValueMapper<u64>;
 
+    // Authorized minters: every charity/project deployed through the factory
+    #[storage_mapper("registered_entity")]
+    fn registered_entity(&self, address: &ManagedAddress) -> SingleValueMapper<EntityInfo<Self::Api>>;
+
+    // Entity directory (so frontends don't have to scrape deploy events)
+    #[storage_mapper("charity_addresses")]
+    fn charity_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("last_entity_id")]
+    fn last_entity_id(&self) -> SingleValueMapper<u64>;
+
+    // Names are unique across charities and projects
+    #[storage_mapper("entity_id_by_name")]
+    fn entity_id_by_name(&self, name: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("entity_address_by_id")]
+    fn entity_address_by_id(&self, entity_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("charity_projects")]
+    fn charity_projects(&self, charity: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("project_parent")]
+    fn project_parent(&self, project: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
     #[storage_mapper("donor_donations_to_entity")]
-    fn donor_donations_to_entity(&self, donor: &ManagedAddress, entity: &ManagedBuffer) -> SingleValueMapper<u64>;
+    fn donor_donations_to_entity(&self, donor: &ManagedAddress, entity_id: u64) -> SingleValueMapper<u64>;
 
+    // Ranking total in EGLD terms (see ranking_value); per-token amounts are in donor_token_amount_to_entity
     #[storage_mapper("donor_total_amount_to_entity")]
-    fn donor_total_amount_to_entity(&self, donor: &ManagedAddress, entity: &ManagedBuffer) -> SingleValueMapper<BigUint>;
+    fn donor_total_amount_to_entity(&self, donor: &ManagedAddress, entity_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("donor_tokens_to_entity")]
+    fn donor_tokens_to_entity(&self, donor: &ManagedAddress, entity_id: u64) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("token_ranking_rate")]
+    fn token_ranking_rate(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<Option<BigUint>>;
+
+    #[storage_mapper("donor_token_amount_to_entity")]
+    fn donor_token_amount_to_entity(
+        &self,
+        donor: &ManagedAddress,
+        entity_id: u64,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("token_donation_total")]
+    fn token_donation_total(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("donor_donation_history")]
     fn donor_donation_history(&self, donor: &ManagedAddress) -> VecMapper<DonationRecord<Self::Api>>;
 
     #[storage_mapper("donor_nft_registry_for_entity")]
-    fn donor_nft_registry_for_entity(&self, donor: &ManagedAddress, entity: &ManagedBuffer) -> VecMapper<u64>;
+    fn donor_nft_registry_for_entity(&self, donor: &ManagedAddress, entity_id: u64) -> VecMapper<u64>;
+
+    // Every badge nonce minted for a donor, across entities
+    #[storage_mapper("donor_badges")]
+    fn donor_badges(&self, donor: &ManagedAddress) -> UnorderedSetMapper<u64>;
 
     #[storage_mapper("entity_donation_history")]
-    fn entity_donation_history(&self, entity: &ManagedBuffer) -> VecMapper<DonationRecord<Self::Api>>;
+    fn entity_donation_history(&self, entity_id: u64) -> VecMapper<DonationRecord<Self::Api>>;
 
     #[storage_mapper("entity_type_donation_history")]
     fn entity_type_donation_history(&self, entity_type: &ManagedBuffer) -> VecMapper<DonationRecord<Self::Api>>;
@@ -1772,13 +3445,35 @@ pub trait PhilanthrifyFactory {
 
     // Transaction NFT tracking (ONE NFT PER ENTITY)
     #[storage_mapper("entity_transaction_nft")]
-    fn entity_transaction_nft(&self, entity_name: &ManagedBuffer) -> SingleValueMapper<u64>;
+    fn entity_transaction_nft(&self, entity_id: u64) -> SingleValueMapper<u64>;
 
+    /// Display-only spending, declared in EGLD; spending paid from the treasury is kept per token in entity_spent_by_token
     #[storage_mapper("entity_transaction_total")]
-    fn entity_transaction_total(&self, entity_name: &ManagedBuffer) -> SingleValueMapper<BigUint>;
+    fn entity_transaction_total(&self, entity_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("entity_spent_tokens")]
+    fn entity_spent_tokens(&self, entity_id: u64) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("entity_spent_by_token")]
+    fn entity_spent_by_token(&self, entity_id: u64, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("entity_transaction_count")]
-    fn entity_transaction_count(&self, entity_name: &ManagedBuffer) -> SingleValueMapper<u64>;
+    fn entity_transaction_count(&self, entity_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("entity_receipt_mode")]
+    fn entity_receipt_mode(&self, entity_id: u64) -> SingleValueMapper<ReceiptMode>;
+
+    #[storage_mapper("spending_records")]
+    fn spending_records(&self, entity_id: u64) -> VecMapper<SpendingRecord<Self::Api>>;
+
+    #[storage_mapper("spending_ids_by_category")]
+    fn spending_ids_by_category(&self, entity_id: u64, category: &ManagedBuffer) -> VecMapper<u64>;
+
+    #[storage_mapper("spending_flag")]
+    fn spending_flag(&self, entity_id: u64, record_id: u64) -> SingleValueMapper<SpendingFlag<Self::Api>>;
+
+    #[storage_mapper("flagged_spending_ids")]
+    fn flagged_spending_ids(&self, entity_id: u64) -> UnorderedSetMapper<u64>;
 
     // NFT Metadata tracking for dynamic updates
     #[storage_mapper("nft_metadata_record")]
@@ -1790,13 +3485,43 @@ pub trait PhilanthrifyFactory {
 
     // Project Patron System
     #[storage_mapper("project_patrons")]
-    fn project_patrons(&self, project_name: &ManagedBuffer) -> VecMapper<PatronRecord<Self::Api>>;
+    fn project_patrons(&self, entity_id: u64) -> VecMapper<PatronRecord<Self::Api>>;
 
-    // Recurring Donation Patterns
+    // Recurring Donation Patterns (platform-wide and per entity)
     #[storage_mapper("donor_recurring_patterns")]
     fn donor_recurring_patterns(&self, donor: &ManagedAddress) -> SingleValueMapper<RecurringPattern>;
 
-    // Tier Base Image URIs (Full images for each tier)
+    #[storage_mapper("donor_entity_recurring_patterns")]
+    fn donor_entity_recurring_patterns(&self, donor: &ManagedAddress, entity_id: u64) -> SingleValueMapper<RecurringPattern>;
+
+    #[storage_mapper("tier_policy")]
+    fn tier_policy(&self) -> SingleValueMapper<TierPolicy>;
+
+    #[storage_mapper("entity_tier_policy")]
+    fn entity_tier_policy(&self, entity_id: u64) -> SingleValueMapper<TierPolicy>;
+
+    #[storage_mapper("amount_format")]
+    fn amount_format(&self) -> SingleValueMapper<AmountFormat>;
+
+    // Option so 0 decimals is distinguishable from not configured
+    #[storage_mapper("token_decimals")]
+    fn token_decimals(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<Option<u32>>;
+
+    #[storage_mapper("badge_custody")]
+    fn badge_custody(&self) -> SingleValueMapper<BadgeCustody>;
+
+    // Option so an explicit Transferable override is distinguishable from no override
+    #[storage_mapper("entity_badge_custody")]
+    fn entity_badge_custody(&self, entity_id: u64) -> SingleValueMapper<Option<BadgeCustody>>;
+
+    // Tier tables (1-based, ordered by thresholds)
+    #[storage_mapper("tiers")]
+    fn tiers(&self) -> VecMapper<TierConfig<Self::Api>>;
+
+    #[storage_mapper("entity_tiers")]
+    fn entity_tiers(&self, entity_id: u64) -> VecMapper<TierConfig<Self::Api>>;
+
+    // Images set for the former fixed Bronze..Platinum tiers; moved into the tier table on upgrade
     #[storage_mapper("tier_bronze_image_uri")]
     fn tier_bronze_image_uri(&self) -> SingleValueMapper<ManagedBuffer>;
 
@@ -1816,9 +3541,15 @@ pub trait PhilanthrifyFactory {
     #[storage_mapper("patron_rest_image_uri")]
     fn patron_rest_image_uri(&self) -> SingleValueMapper<ManagedBuffer>;
 
-    // Temporary storage for sorting patrons
-    #[storage_mapper("temp_sorted_patrons")]
-    fn temp_sorted_patrons(&self) -> VecMapper<PatronRecord<Self::Api>>;
+    #[storage_mapper("entity_leaderboard_size")]
+    fn entity_leaderboard_size(&self, entity_id: u64) -> SingleValueMapper<usize>;
+
+    // Every donor of an entity, for ranks below the leaderboard
+    #[storage_mapper("entity_donors")]
+    fn entity_donors(&self, entity_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("donor_first_donation_timestamp")]
+    fn donor_first_donation_timestamp(&self, donor: &ManagedAddress, entity_id: u64) -> SingleValueMapper<u64>;
 
     // Patron badge URI added (0=none, 1=top1 image, 2=rest image) so we add the other when rank changes
     #[storage_mapper("patron_image_type_added")]
@@ -1826,4 +3557,9 @@ pub trait PhilanthrifyFactory {
 
     #[storage_mapper("has_patron_uri")]
     fn has_patron_uri(&self, nft_nonce: u64) -> SingleValueMapper<bool>;
+
+    /// Set once the system contract confirmed the freeze of a retrieved badge, cleared on unfreeze
+    #[view(isBadgeFrozen)]
+    #[storage_mapper("badge_frozen")]
+    fn badge_frozen(&self, nft_nonce: u64) -> SingleValueMapper<bool>;
 }
\ No newline at end of file
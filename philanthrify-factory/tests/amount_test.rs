@@ -0,0 +1,40 @@
+use multiversx_sc::types::BigUint;
+use multiversx_sc_scenario::api::StaticApi;
+use philanthrify_factory::amount::format_amount;
+
+const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+
+fn format(amount: BigUint<StaticApi>, decimals: u32, precision: u32) -> String {
+    String::from_utf8(format_amount(&amount, decimals, precision).to_vec()).unwrap()
+}
+
+#[test]
+fn whole_and_fractional_egld() {
+    assert_eq!(format(BigUint::zero(), 18, 4), "0");
+    assert_eq!(format(BigUint::from(ONE_EGLD), 18, 4), "1");
+    assert_eq!(format(BigUint::from(ONE_EGLD) * 12u32 + BigUint::from(ONE_EGLD / 2), 18, 4), "12.5");
+    assert_eq!(format(BigUint::from(ONE_EGLD / 20), 18, 4), "0.05");
+    assert_eq!(format(BigUint::from(ONE_EGLD / 1_000), 18, 4), "0.001");
+}
+
+#[test]
+fn amounts_beyond_u64_keep_full_precision() {
+    // 1_000_000.25 EGLD is far above u64::MAX in atto units
+    let amount = BigUint::from(ONE_EGLD) * 1_000_000u32 + BigUint::from(ONE_EGLD / 4);
+    assert_eq!(format(amount, 18, 4), "1000000.25");
+}
+
+#[test]
+fn rounds_half_up_to_precision() {
+    assert_eq!(format(BigUint::from(123_456u64), 6, 2), "0.12");
+    assert_eq!(format(BigUint::from(125_000u64), 6, 2), "0.13");
+    assert_eq!(format(BigUint::from(999_999u64), 6, 2), "1");
+    assert_eq!(format(BigUint::from(ONE_EGLD / 100_000), 18, 4), "0");
+}
+
+#[test]
+fn precision_is_capped_by_decimals() {
+    assert_eq!(format(BigUint::from(1_250u64), 2, 4), "12.5");
+    assert_eq!(format(BigUint::from(1_250u64), 0, 4), "1250");
+    assert_eq!(format(BigUint::from(1_250u64), 3, 0), "1");
+}
@@ -0,0 +1,93 @@
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::api::StaticApi;
+use philanthrify_factory::attributes::{escape_json, sanitize_tag, NftAttributes};
+
+fn buffer(value: &str) -> ManagedBuffer<StaticApi> {
+    ManagedBuffer::from(value)
+}
+
+fn text(value: ManagedBuffer<StaticApi>) -> String {
+    String::from_utf8(value.to_vec()).unwrap()
+}
+
+#[test]
+fn builds_all_sections_in_order() {
+    let mut attributes = NftAttributes::new(&buffer("bafycid"));
+    attributes.tag_bytes(b"donation");
+    attributes.tag_with_value(b"tier:", &buffer("Gold"));
+    attributes.image(&buffer("https://img/gold"));
+    attributes.add_trait(b"Tier", &buffer("Gold"));
+    attributes.add_trait_bytes(b"Patron", b"No");
+
+    assert_eq!(
+        text(attributes.build()),
+        "metadata:bafycid;tags:donation,tier:Gold;image:https://img/gold;\
+         traits:[{\"trait_type\":\"Tier\",\"value\":\"Gold\"},{\"trait_type\":\"Patron\",\"value\":\"No\"}]"
+    );
+}
+
+#[test]
+fn optional_sections_are_left_out() {
+    let mut attributes = NftAttributes::new(&ManagedBuffer::new());
+    attributes.tag(&ManagedBuffer::new());
+    attributes.tag_bytes(b"receipt");
+
+    assert_eq!(text(attributes.build()), "tags:receipt");
+    assert_eq!(text(attributes.traits_json()), "[]");
+}
+
+#[test]
+fn tags_cannot_inject_tags_or_sections() {
+    let mut attributes = NftAttributes::new(&ManagedBuffer::new());
+    attributes.tag(&buffer("nice,patron_1"));
+    attributes.tag(&buffer("x;traits:[{\"trait_type\":\"Patron\"}]"));
+    attributes.tag_with_value(b"tier:", &buffer("Gold;image:evil"));
+
+    assert_eq!(
+        text(attributes.build()),
+        "tags:nice_patron_1,x_traits:[{\"trait_type\":\"Patron\"}],tier:Gold_image:evil"
+    );
+    assert_eq!(text(sanitize_tag(&buffer("plain"))), "plain");
+}
+
+#[test]
+fn trait_values_cannot_break_json() {
+    let mut attributes = NftAttributes::new(&ManagedBuffer::new());
+    attributes.add_trait(b"Description", &buffer("\"},{\"trait_type\":\"Patron\",\"value\":\"Yes"));
+
+    assert_eq!(
+        text(attributes.traits_json()),
+        "[{\"trait_type\":\"Description\",\"value\":\"\\\"},{\\\"trait_type\\\":\\\"Patron\\\",\\\"value\\\":\\\"Yes\"}]"
+    );
+}
+
+#[test]
+fn trait_values_cannot_add_sections() {
+    let mut attributes = NftAttributes::new(&ManagedBuffer::new());
+    attributes.add_trait(b"Description", &buffer("paid;image:https://evil"));
+    attributes.add_trait(b"Category", &buffer("Food;tags:patron_1"));
+
+    let built = text(attributes.build());
+    assert_eq!(
+        built,
+        "tags:;traits:[{\"trait_type\":\"Description\",\"value\":\"paid\\u003bimage:https://evil\"},\
+         {\"trait_type\":\"Category\",\"value\":\"Food\\u003btags:patron_1\"}]"
+    );
+    assert_eq!(built.matches(';').count(), 1);
+}
+
+#[test]
+fn escapes_backslashes_and_control_characters() {
+    assert_eq!(text(escape_json(&buffer("a\\b"))), "a\\\\b");
+    assert_eq!(text(escape_json(&buffer("line\nbreak\ttab"))), "line\\nbreak\\ttab");
+    assert_eq!(text(escape_json(&buffer("bell\u{7}"))), "bell\\u0007");
+    assert_eq!(text(escape_json(&buffer("Ocean Cleanup"))), "Ocean Cleanup");
+}
+
+#[test]
+fn metadata_and_image_cannot_add_sections() {
+    let mut attributes = NftAttributes::new(&buffer("cid;traits:[]"));
+    attributes.image(&buffer("https://img;traits:[]"));
+
+    assert_eq!(text(attributes.build()), "metadata:cid_traits:[];tags:;image:https://img_traits:[]");
+}
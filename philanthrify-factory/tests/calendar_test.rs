@@ -0,0 +1,35 @@
+use philanthrify_factory::calendar::{civil_from_timestamp, month_index, month_of, quarter_index, quarter_of};
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn epoch_and_known_dates() {
+    assert_eq!(civil_from_timestamp(0), (1970, 1, 1));
+    assert_eq!(civil_from_timestamp(DAY - 1), (1970, 1, 1));
+    assert_eq!(civil_from_timestamp(1_768_435_200), (2026, 1, 15));
+    assert_eq!(civil_from_timestamp(1_798_761_599), (2026, 12, 31));
+}
+
+#[test]
+fn leap_years() {
+    // 2024-02-29 exists, 2100 is not a leap year, 2000 is
+    assert_eq!(civil_from_timestamp(1_709_164_800), (2024, 2, 29));
+    assert_eq!(civil_from_timestamp(1_709_164_800 + DAY), (2024, 3, 1));
+    assert_eq!(civil_from_timestamp(951_782_400), (2000, 2, 29));
+    assert_eq!(civil_from_timestamp(4_107_456_000), (2100, 2, 28));
+    assert_eq!(civil_from_timestamp(4_107_456_000 + DAY), (2100, 3, 1));
+}
+
+#[test]
+fn months_and_quarters_across_year_boundary() {
+    let dec_31_2025 = 1_767_139_200;
+    let jan_1_2026 = dec_31_2025 + DAY;
+
+    assert_eq!(month_of(dec_31_2025), 202512);
+    assert_eq!(month_of(jan_1_2026), 202601);
+    assert_eq!(month_index(202601) - month_index(202512), 1);
+
+    assert_eq!(quarter_of(dec_31_2025), 20254);
+    assert_eq!(quarter_of(jan_1_2026), 20261);
+    assert_eq!(quarter_index(20261) - quarter_index(20254), 1);
+}
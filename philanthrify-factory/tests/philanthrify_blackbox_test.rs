@@ -0,0 +1,2280 @@
+use multiversx_sc_scenario::{imports::*, scenario_model::Log};
+
+use philanthrify_charity::{factory_proxy, project_proxy};
+use philanthrify_factory::charity_proxy;
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const DONOR_1: TestAddress = TestAddress::new("donor-1");
+const DONOR_2: TestAddress = TestAddress::new("donor-2");
+const PROCESSOR: TestAddress = TestAddress::new("processor");
+
+const FACTORY: TestSCAddress = TestSCAddress::new("factory");
+const CHARITY_TEMPLATE: TestSCAddress = TestSCAddress::new("charity-template");
+const PROJECT_TEMPLATE: TestSCAddress = TestSCAddress::new("project-template");
+
+const FACTORY_CODE_PATH: MxscPath = MxscPath::new("output/philanthrify-factory.mxsc.json");
+const CHARITY_CODE_PATH: MxscPath = MxscPath::new("../philanthrify-charity/output/philanthrify-charity.mxsc.json");
+const PROJECT_CODE_PATH: MxscPath = MxscPath::new("../philanthrify-project/output/philanthrify-project.mxsc.json");
+
+const NFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("PHILXY-123456");
+const USDC_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+
+const CHARITY_NAME: &str = "Ocean Cleanup";
+const PROJECT_NAME: &str = "Beach Day";
+
+// 2025-12-15, 2026-01-15 and 2026-02-15, 00:00 UTC
+const DEC_15_2025: u64 = 1_765_756_800;
+const JAN_15_2026: u64 = 1_768_435_200;
+const FEB_15_2026: u64 = 1_771_113_600;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("philanthrify-factory");
+    blockchain.register_contract(FACTORY_CODE_PATH, philanthrify_factory::ContractBuilder);
+    blockchain.register_contract(CHARITY_CODE_PATH, philanthrify_charity::ContractBuilder);
+    blockchain.register_contract(PROJECT_CODE_PATH, philanthrify_project::ContractBuilder);
+    blockchain
+}
+
+struct PhilanthrifyTestState {
+    world: ScenarioWorld,
+    charity: Address,
+    project: Address,
+}
+
+impl PhilanthrifyTestState {
+    /// Factory with templates and a mock NFT collection, one charity and one project under it.
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER).nonce(1).balance(1_000_000);
+        world.account(DONOR_1).nonce(1).balance(1_000_000);
+        world.account(DONOR_2).nonce(1).balance(1_000_000).esdt_balance(USDC_TOKEN_ID, 100_000_000);
+        world.account(PROCESSOR).nonce(1);
+        world.account(CHARITY_TEMPLATE).code(CHARITY_CODE_PATH);
+        world.account(PROJECT_TEMPLATE).code(PROJECT_CODE_PATH);
+        world.current_block().block_timestamp(JAN_15_2026);
+
+        world
+            .tx()
+            .from(OWNER)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .init(OWNER)
+            .code(FACTORY_CODE_PATH)
+            .new_address(FACTORY)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER)
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .set_charity_template(CHARITY_TEMPLATE)
+            .run();
+        world
+            .tx()
+            .from(OWNER)
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .set_project_template(PROJECT_TEMPLATE)
+            .run();
+        world
+            .tx()
+            .from(OWNER)
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .set_global_nft_collection(NFT_TOKEN_ID)
+            .run();
+        world.set_esdt_local_roles(
+            FACTORY,
+            NFT_TOKEN_ID.as_bytes(),
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftUpdateAttributes,
+                EsdtLocalRole::NftAddUri,
+            ],
+        );
+
+        let charity = world
+            .tx()
+            .from(OWNER)
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .deploy_charity(CHARITY_NAME)
+            .returns(ReturnsResult)
+            .run()
+            .to_address();
+
+        let project = world
+            .tx()
+            .from(OWNER)
+            .to(&charity)
+            .typed(charity_proxy::PhilanthrifyCharityProxy)
+            .deploy_project(PROJECT_NAME)
+            .returns(ReturnsResult)
+            .run()
+            .to_address();
+
+        Self { world, charity, project }
+    }
+
+    fn donate_to_project(&mut self, donor: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(donor)
+            .to(&self.project)
+            .typed(project_proxy::PhilanthrifyProjectProxy)
+            .donate_to_project(ManagedBuffer::new(), MultiValueEncoded::new())
+            .egld(amount)
+            .run();
+    }
+
+    fn donate_to_charity(&mut self, donor: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(donor)
+            .to(&self.charity)
+            .typed(charity_proxy::PhilanthrifyCharityProxy)
+            .donate_to_charity(ManagedBuffer::new(), MultiValueEncoded::new())
+            .egld(amount)
+            .run();
+    }
+
+    fn donate_usdc_to_project(&mut self, donor: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(donor)
+            .to(&self.project)
+            .typed(project_proxy::PhilanthrifyProjectProxy)
+            .donate_to_project(ManagedBuffer::new(), MultiValueEncoded::new())
+            .single_esdt(&USDC_TOKEN_ID.to_token_identifier(), 0, &BigUint::from(amount))
+            .run();
+    }
+
+    /// Lets the project take USDC and tells the factory it has 6 decimals.
+    fn accept_usdc(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(&self.project)
+            .typed(project_proxy::PhilanthrifyProjectProxy)
+            .add_accepted_token(USDC_TOKEN_ID)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .set_token_decimals(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID), 6u32)
+            .run();
+    }
+
+    fn transaction_for_project(&mut self, amount: u64, category: &str, description: &str) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(&self.project)
+            .typed(project_proxy::PhilanthrifyProjectProxy)
+            .transaction_for_project(amount, category, description, ManagedBuffer::new())
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    fn entity_id(&mut self, name: &str) -> u64 {
+        self.world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_entity_id_by_name(name)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+            .expect("entity not registered")
+    }
+
+    fn donor_nfts(&mut self, donor: TestAddress, entity_id: u64) -> Vec<u64> {
+        self.world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_donor_nfts_for_entity(donor, entity_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter().collect()
+    }
+
+    fn nft_metadata(&mut self, nonce: u64) -> factory_proxy::NftMetadataRecord<StaticApi> {
+        self.world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_nft_metadata(nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn badge_locations(&mut self, donor: TestAddress) -> (Vec<u64>, Vec<u64>) {
+        let (held, withdrawn) = self
+            .world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_donor_badge_locations(donor)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (held.into_iter().collect(), withdrawn.into_iter().collect())
+    }
+
+    fn patron_rank(&mut self, donor: TestAddress, entity_id: u64) -> Option<u64> {
+        self.world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_donor_patron_rank_view(donor, entity_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    fn leaderboard_rank(&mut self, donor: TestAddress, entity_id: u64) -> Option<u64> {
+        self.world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_donor_leaderboard_rank(donor, entity_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    fn attributes_preview(&mut self, donor: TestAddress, entity_id: u64) -> String {
+        let attributes = self
+            .world
+            .query()
+            .to(FACTORY)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .get_donor_nft_attributes_preview(donor, entity_id)
+            .returns(ReturnsResult)
+            .run();
+        String::from_utf8(attributes.to_vec()).unwrap()
+    }
+}
+
+fn has_event(logs: &[Log], identifier: &str) -> bool {
+    logs.iter()
+        .any(|log| log.topics.first().map(|topic| topic.as_slice()) == Some(identifier.as_bytes()))
+}
+
+#[test]
+fn deploy_registers_charity_and_project() {
+    let mut state = PhilanthrifyTestState::new();
+
+    assert_eq!(state.entity_id(CHARITY_NAME), 1);
+    assert_eq!(state.entity_id(PROJECT_NAME), 2);
+
+    let charity_count = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_charity_count()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(charity_count, 1);
+
+    let project_charity = state
+        .world
+        .query()
+        .to(&state.project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .charity_address()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(project_charity.to_address(), state.charity);
+}
+
+#[test]
+fn donations_mint_one_badge_and_upgrade_tier() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    state.donate_to_project(DONOR_1, 100);
+    assert_eq!(state.donor_nfts(DONOR_1, project_id), vec![1]);
+    let metadata = state.nft_metadata(1);
+    assert_eq!(metadata.donation_count, 1);
+    assert_eq!(metadata.tier_level, 1);
+    assert!(state.attributes_preview(DONOR_1, project_id).contains(",bronze,"));
+
+    state.donate_to_project(DONOR_1, 200);
+    state.donate_to_project(DONOR_1, 300);
+
+    // Further donations update the same badge instead of minting new ones
+    assert_eq!(state.donor_nfts(DONOR_1, project_id), vec![1]);
+    let metadata = state.nft_metadata(1);
+    assert_eq!(metadata.nft_nonce, 1);
+    assert_eq!(metadata.entity_id, project_id);
+    assert_eq!(metadata.donor_address.to_address(), DONOR_1.to_address());
+    assert_eq!(metadata.donation_count, 3);
+    assert_eq!(metadata.tier_level, 2);
+    assert_eq!(metadata.total_amount, BigUint::from(600u64));
+    assert_eq!(metadata.last_amount, BigUint::from(300u64));
+    assert!(metadata.is_on_contract);
+    assert!(state.attributes_preview(DONOR_1, project_id).contains(",silver,"));
+
+    // The badge stays on the factory and carries the same attributes the preview view computes
+    let attributes = state.attributes_preview(DONOR_1, project_id);
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, attributes.as_str());
+    state.world.check_account(&state.project).balance(600);
+}
+
+#[test]
+fn badge_amounts_use_configured_decimals() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_amount_format(2u32, 2u32)
+        .run();
+    state.donate_to_project(DONOR_1, 1_000);
+    state.donate_to_project(DONOR_1, 250);
+
+    let attributes = state.attributes_preview(DONOR_1, project_id);
+    assert!(attributes.contains(",donated$12.5,"));
+    assert!(attributes.contains("{\"trait_type\":\"Last Donation\",\"value\":\"2.5 EGLD\"}"));
+
+    state.transaction_for_project(1_999, "Supplies", "Gloves");
+    let receipt = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_transaction_nft_for_entity(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .expect("receipt minted");
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, receipt.nft_nonce, 1, "tags:transaction1,spent$19.99,Beach Day,receipt,philanthrify,project,transparency,impact");
+
+    // A 6-decimal token is shown in its own decimals next to the EGLD total
+    state.accept_usdc();
+    state.donate_to_project(DONOR_2, 1_000);
+    state.donate_usdc_to_project(DONOR_2, 1_500_000);
+    let attributes = state.attributes_preview(DONOR_2, project_id);
+    assert!(attributes.contains(",donated$10 EGLD+1.5 USDC,"));
+    assert!(attributes.contains("{\"trait_type\":\"Total Donated\",\"value\":\"10 EGLD+1.5 USDC\"}"));
+    assert!(attributes.contains("{\"trait_type\":\"Last Donation\",\"value\":\"1.5 USDC\"}"));
+}
+
+#[test]
+fn entity_tier_table_overrides_platform_tiers() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    let mut tiers = MultiValueEncoded::new();
+    tiers.push(MultiValue5::from((ManagedBuffer::from("Seed"), ManagedBuffer::from("seed"), ManagedBuffer::from("https://img/seed"), 1u64, BigUint::zero())));
+    tiers.push(MultiValue5::from((ManagedBuffer::from("Sprout"), ManagedBuffer::from("sprout"), ManagedBuffer::from("https://img/sprout"), 2u64, BigUint::zero())));
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_tiers(project_id, tiers)
+        .run();
+
+    state.donate_to_project(DONOR_1, 100);
+    state.donate_to_project(DONOR_1, 100);
+
+    assert_eq!(state.nft_metadata(1).tier_level, 2);
+    let attributes = state.attributes_preview(DONOR_1, project_id);
+    assert!(attributes.contains(",sprout,"));
+    assert!(attributes.contains(";image:https://img/sprout"));
+
+    // A fifth platform tier does not leak into the overridden entity
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .add_tier("Diamond", "diamond", "https://img/diamond", 20u64, 0u64)
+        .run();
+    let platform_tiers: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_tiers()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(platform_tiers.len(), 5);
+    let entity_tiers: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_entity_tiers(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(entity_tiers.len(), 2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .add_tier("Stone", "stone", "https://img/stone", 15u64, 0u64)
+        .with_result(ExpectError(4, "Tier thresholds must increase"))
+        .run();
+}
+
+#[test]
+fn amount_policy_ranks_one_large_donation_above_many_small_ones() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    let mut tiers = MultiValueEncoded::new();
+    tiers.push(MultiValue5::from((ManagedBuffer::from("Friend"), ManagedBuffer::from("friend"), ManagedBuffer::from("https://img/friend"), 1u64, BigUint::zero())));
+    tiers.push(MultiValue5::from((ManagedBuffer::from("Regular"), ManagedBuffer::from("regular"), ManagedBuffer::from("https://img/regular"), 3u64, BigUint::from(1_000u64))));
+    tiers.push(MultiValue5::from((ManagedBuffer::from("Benefactor"), ManagedBuffer::from("benefactor"), ManagedBuffer::from("https://img/benefactor"), 10u64, BigUint::from(10_000u64))));
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_tiers(project_id, tiers)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_tier_policy(project_id, factory_proxy::TierPolicy::TotalAmount)
+        .run();
+
+    for _ in 0..3 {
+        state.donate_to_project(DONOR_1, 1);
+    }
+    state.donate_to_project(DONOR_2, 10_000);
+    let donor_1_badge = state.donor_nfts(DONOR_1, project_id)[0];
+    let donor_2_badge = state.donor_nfts(DONOR_2, project_id)[0];
+    assert_eq!(state.nft_metadata(donor_1_badge).tier_level, 1);
+    assert_eq!(state.nft_metadata(donor_2_badge).tier_level, 3);
+
+    // Half count, half amount: all 3 donations but 3 of 1000 donated is not enough for Regular
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_tier_policy(project_id, factory_proxy::TierPolicy::Weighted { count_weight_bps: 5_000 })
+        .run();
+    assert!(state.attributes_preview(DONOR_1, project_id).contains(",friend,"));
+    // 4/3 donations and 703/1000 donated average out above the Regular threshold
+    state.donate_to_project(DONOR_1, 700);
+    assert_eq!(state.nft_metadata(donor_1_badge).tier_level, 2);
+}
+
+#[test]
+fn patron_ranking_follows_total_donated() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    state.donate_to_project(DONOR_1, 100);
+    state.donate_to_project(DONOR_2, 300);
+
+    let patrons: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_project_patrons(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter().collect();
+    assert_eq!(patrons.len(), 2);
+    assert_eq!(patrons[0].donor_address.to_address(), DONOR_2.to_address());
+    assert_eq!(patrons[0].patron_rank, 1);
+    assert_eq!(patrons[1].donor_address.to_address(), DONOR_1.to_address());
+    assert_eq!(patrons[1].patron_rank, 2);
+
+    // Overtaking the leader swaps the ranks
+    state.donate_to_project(DONOR_1, 500);
+    let rank = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_patron_rank_view(DONOR_1, project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option();
+    assert_eq!(rank, Some(1));
+    assert!(state.attributes_preview(DONOR_2, project_id).contains(",patron_2,"));
+}
+
+#[test]
+fn leaderboard_size_evicts_and_readmits_donors() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_leaderboard_size(project_id, 1usize)
+        .run();
+
+    // Equal totals keep the earlier donor on top
+    state.donate_to_project(DONOR_1, 100);
+    state.world.current_block().block_timestamp(JAN_15_2026 + 60);
+    state.donate_to_project(DONOR_2, 100);
+    assert_eq!(state.patron_rank(DONOR_1, project_id), Some(1));
+    assert_eq!(state.patron_rank(DONOR_2, project_id), None);
+    assert_eq!(state.leaderboard_rank(DONOR_2, project_id), Some(2));
+
+    // Overtaking evicts the previous patron and clears their patron tag
+    state.donate_to_project(DONOR_2, 50);
+    assert_eq!(state.patron_rank(DONOR_2, project_id), Some(1));
+    assert_eq!(state.patron_rank(DONOR_1, project_id), None);
+    assert_eq!(state.leaderboard_rank(DONOR_1, project_id), Some(2));
+    assert!(!state.attributes_preview(DONOR_1, project_id).contains(",patron_1,"));
+    assert!(state.attributes_preview(DONOR_1, project_id).contains(",patron_rank:0"));
+
+    // Evicted donors come back with their original first-donation timestamp
+    state.donate_to_project(DONOR_1, 100);
+    let patrons: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_project_patrons(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(patrons.len(), 1);
+    assert_eq!(patrons[0].donor_address.to_address(), DONOR_1.to_address());
+    assert_eq!(patrons[0].since_timestamp, JAN_15_2026);
+    assert_eq!(state.leaderboard_rank(DONOR_2, project_id), Some(2));
+
+    // Growing the board re-admits donors below it without waiting for another donation
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_leaderboard_size(project_id, 10usize)
+        .run();
+    assert_eq!(state.patron_rank(DONOR_2, project_id), Some(2));
+    let donor_2_badge = state.donor_nfts(DONOR_2, project_id)[0];
+    let readmitted = state.attributes_preview(DONOR_2, project_id);
+    assert!(readmitted.contains(",patron_rank:2,"));
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, donor_2_badge, 1, readmitted.as_str());
+}
+
+#[test]
+fn soulbound_badges_cannot_be_retrieved() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.donate_to_project(DONOR_1, 100);
+    let attributes = state.attributes_preview(DONOR_1, project_id);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_badge_custody(project_id, factory_proxy::BadgeCustody::Soulbound)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_donor_nft(project_id)
+        .with_result(ExpectError(4, "Badges for this entity are soulbound"))
+        .run();
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, attributes.as_str());
+}
+
+#[test]
+fn frozen_custody_only_reports_confirmed_freezes() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.donate_to_project(DONOR_1, 100);
+    let attributes = state.attributes_preview(DONOR_1, project_id);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_badge_custody(project_id, factory_proxy::BadgeCustody::RetrievableFrozen)
+        .run();
+
+    // Frozen-custody badges never go out through the bulk retrieval
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_all_donor_nfts(10usize, MultiValueEncoded::new())
+        .with_result(ExpectError(4, "No badges to retrieve"))
+        .run();
+
+    let logs = state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_donor_nft(project_id)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "donor_nft_retrieved"));
+    state
+        .world
+        .check_account(DONOR_1)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, attributes.as_str());
+
+    // The freeze runs asynchronously on the system contract; until its callback confirms it the badge is not reported frozen
+    let frozen = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .badge_frozen(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(!frozen);
+
+    // Only NFT managers may unfreeze a badge so it can be returned
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .unfreeze_donor_nft(DONOR_1, 1u64)
+        .with_result(ExpectError(4, "Only global admin, platform admin or NFT manager allowed"))
+        .run();
+}
+
+#[test]
+fn returned_badge_catches_up_on_donations() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.donate_to_project(DONOR_1, 100);
+    let minted_attributes = state.attributes_preview(DONOR_1, project_id);
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_donor_nft(project_id)
+        .run();
+    state
+        .world
+        .check_account(DONOR_1)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, minted_attributes.as_str());
+    assert!(!state.nft_metadata(1).is_on_contract);
+    assert_eq!(state.badge_locations(DONOR_1), (vec![], vec![1]));
+
+    // Donations made while the badge is in the wallet are not reflected on it
+    state.donate_to_project(DONOR_1, 200);
+    state.donate_to_project(DONOR_1, 300);
+
+    // Someone else cannot deposit it on the donor's behalf
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(DONOR_2)
+        .single_esdt(&NFT_TOKEN_ID.into(), 1, &BigUint::from(1u64))
+        .raw_call("")
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .return_donor_nft()
+        .single_esdt(&NFT_TOKEN_ID.into(), 1, &BigUint::from(1u64))
+        .with_result(ExpectError(4, "Only the donor can return this badge"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(DONOR_1)
+        .single_esdt(&NFT_TOKEN_ID.into(), 1, &BigUint::from(1u64))
+        .raw_call("")
+        .run();
+
+    let logs = state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .return_donor_nft()
+        .single_esdt(&NFT_TOKEN_ID.into(), 1, &BigUint::from(1u64))
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "donor_nft_returned"));
+    assert!(state.nft_metadata(1).is_on_contract);
+    assert_eq!(state.badge_locations(DONOR_1), (vec![1], vec![]));
+
+    let attributes = state.attributes_preview(DONOR_1, project_id);
+    assert!(attributes.contains(",silver,"));
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, attributes.as_str());
+}
+
+#[test]
+fn retrieve_all_badges_in_one_transfer() {
+    let mut state = PhilanthrifyTestState::new();
+    let charity_id = state.entity_id(CHARITY_NAME);
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.donate_to_charity(DONOR_1, 100);
+    state.donate_to_project(DONOR_1, 100);
+    let charity_badge = state.donor_nfts(DONOR_1, charity_id)[0];
+    let project_badge = state.donor_nfts(DONOR_1, project_id)[0];
+
+    // The entity filter limits the transfer to the project badge
+    let mut filter = MultiValueEncoded::new();
+    filter.push(project_id);
+    let logs = state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_all_donor_nfts(10usize, filter)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "donor_nfts_retrieved"));
+    assert_eq!(state.badge_locations(DONOR_1), (vec![charity_badge], vec![project_badge]));
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_all_donor_nfts(10usize, MultiValueEncoded::new())
+        .run();
+    let charity_attributes = state.attributes_preview(DONOR_1, charity_id);
+    state
+        .world
+        .check_account(DONOR_1)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, charity_badge, 1, charity_attributes.as_str());
+    let (held, mut withdrawn) = state.badge_locations(DONOR_1);
+    withdrawn.sort();
+    assert!(held.is_empty());
+    assert_eq!(withdrawn, vec![charity_badge.min(project_badge), charity_badge.max(project_badge)]);
+    assert!(!state.nft_metadata(charity_badge).is_on_contract);
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .retrieve_all_donor_nfts(10usize, MultiValueEncoded::new())
+        .with_result(ExpectError(4, "No badges to retrieve"))
+        .run();
+}
+
+#[test]
+fn display_only_donations_need_owner_or_processor() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    let project = state.project.clone();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .set_display_only_donations(true)
+        .run();
+
+    // A donor cannot mint themselves a badge for an arbitrary amount
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .donate_display_only(DONOR_1, 1_000_000u64, ManagedBuffer::new(), MultiValueEncoded::new())
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+    assert!(state.donor_nfts(DONOR_1, project_id).is_empty());
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .grant_role(project_proxy::Role::Processor, PROCESSOR)
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .donate_display_only(DONOR_1, 250u64, ManagedBuffer::new(), MultiValueEncoded::new())
+        .run();
+    let badge = state.donor_nfts(DONOR_1, project_id)[0];
+    assert_eq!(state.nft_metadata(badge).total_amount, BigUint::from(250u64));
+}
+
+#[test]
+fn badge_total_amount_is_always_in_ranking_terms() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.accept_usdc();
+
+    // Without a ranking rate the first mint records no EGLD value, not the raw 5_000_000 units
+    state.donate_usdc_to_project(DONOR_2, 5_000_000);
+    let badge = state.donor_nfts(DONOR_2, project_id)[0];
+    assert_eq!(state.nft_metadata(badge).total_amount, BigUint::zero());
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_token_ranking_rate(USDC_TOKEN_ID, 1_000u64)
+        .run();
+    state.donate_usdc_to_project(DONOR_2, 2_000_000);
+    let metadata = state.nft_metadata(badge);
+    assert_eq!(metadata.total_amount, BigUint::from(2_000u64));
+    assert_eq!(metadata.last_amount, BigUint::from(2_000_000u64));
+}
+
+#[test]
+fn donations_in_other_tokens_rank_by_egld_value() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.accept_usdc();
+
+    state.donate_to_project(DONOR_1, 1_000);
+    // 5 USDC is 5_000_000 raw units but has no ranking rate yet, so it cannot outrank 1000 atto EGLD
+    state.donate_usdc_to_project(DONOR_2, 5_000_000);
+    assert_eq!(state.patron_rank(DONOR_1, project_id), Some(1));
+    assert_eq!(state.patron_rank(DONOR_2, project_id), None);
+
+    // 1 USDC counts as 1000 atto EGLD from now on
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_token_ranking_rate(USDC_TOKEN_ID, 1_000u64)
+        .run();
+    state.donate_usdc_to_project(DONOR_2, 2_000_000);
+    assert_eq!(state.patron_rank(DONOR_2, project_id), Some(1));
+    assert_eq!(state.patron_rank(DONOR_1, project_id), Some(2));
+
+    let totals: Vec<(String, u64)> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_token_totals(DONOR_2, project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|entry| {
+            let (token, total) = entry.into_tuple();
+            (String::from_utf8(token.into_name().to_vec()).unwrap(), total.to_u64().unwrap())
+        })
+        .collect();
+    assert_eq!(totals, vec![("USDC-123456".to_string(), 7_000_000)]);
+}
+
+#[test]
+fn donation_only_rewrites_overtaken_patron_badges() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    let project = state.project.clone();
+
+    let mut tags = MultiValueEncoded::new();
+    tags.push(ManagedBuffer::from("gala"));
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .donate_to_project(ManagedBuffer::new(), tags)
+        .egld(1_000)
+        .run();
+    let donor_1_badge = state.donor_nfts(DONOR_1, project_id)[0];
+
+    // A donor entering below #1 leaves the #1 badge, and its custom tag, untouched
+    state.donate_to_project(DONOR_2, 500);
+    assert_eq!(state.patron_rank(DONOR_2, project_id), Some(2));
+    let untouched = state.attributes_preview(DONOR_1, project_id).replacen("tags:", "tags:gala,", 1);
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, donor_1_badge, 1, untouched.as_str());
+
+    // Overtaking #1 rewrites its badge for the new rank
+    state.donate_to_project(DONOR_2, 600);
+    assert_eq!(state.patron_rank(DONOR_1, project_id), Some(2));
+    let rewritten = state.attributes_preview(DONOR_1, project_id);
+    assert!(rewritten.contains(",patron_rank:2,"));
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, donor_1_badge, 1, rewritten.as_str());
+}
+
+#[test]
+fn batch_donation_settles_distinct_donors() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    let mut vip_tags = ManagedVec::new();
+    vip_tags.push(ManagedBuffer::from("gala"));
+    let mut donations = MultiValueEncoded::new();
+    donations.push(project_proxy::DonationItem {
+        donor: DONOR_1.to_managed_address(),
+        amount: BigUint::from(100u64),
+        user_image_uri: ManagedBuffer::new(),
+        tags: ManagedVec::new(),
+    });
+    donations.push(project_proxy::DonationItem {
+        donor: DONOR_2.to_managed_address(),
+        amount: BigUint::from(250u64),
+        user_image_uri: ManagedBuffer::from("bafyimage"),
+        tags: vip_tags,
+    });
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&state.project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .set_display_only_donations(true)
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&state.project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .batch_donate_to_project(donations.clone())
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&state.project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .grant_role(project_proxy::Role::Processor, PROCESSOR)
+        .run();
+    let logs = state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&state.project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .batch_donate_to_project(donations)
+        .gas(50_000_000)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "batch_event"));
+    assert!(has_event(&logs, "donation_batch_recorded"));
+
+    // The leaderboard is settled at the end: the larger gift overtakes the earlier one
+    assert_eq!(state.patron_rank(DONOR_2, project_id), Some(1));
+    assert_eq!(state.patron_rank(DONOR_1, project_id), Some(2));
+
+    // Each donor gets their own badge with their own amount and tags; the image is not mistaken for a tag
+    let donor_1_badge = state.donor_nfts(DONOR_1, project_id)[0];
+    let donor_2_badge = state.donor_nfts(DONOR_2, project_id)[0];
+    assert_ne!(donor_1_badge, donor_2_badge);
+    assert_eq!(state.nft_metadata(donor_1_badge).total_amount, BigUint::from(100u64));
+    assert_eq!(state.nft_metadata(donor_2_badge).total_amount, BigUint::from(250u64));
+    // The preview leaves out custom tags, so the minted badge is the preview with the donor's tag first
+    let donor_1_attributes = state.attributes_preview(DONOR_1, project_id);
+    let donor_2_attributes = state.attributes_preview(DONOR_2, project_id).replacen("tags:", "tags:gala,", 1);
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, donor_1_badge, 1, donor_1_attributes.as_str())
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, donor_2_badge, 1, donor_2_attributes.as_str());
+    state.world.check_account(&state.project).balance(0);
+}
+
+#[test]
+fn consecutive_months_build_recurring_streak() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    state.donate_to_project(DONOR_1, 100);
+    state.world.current_block().block_timestamp(FEB_15_2026);
+    state.donate_to_project(DONOR_1, 100);
+
+    let pattern = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_recurring_pattern(DONOR_1)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(pattern.monthly_streak, 2);
+    assert!(state.attributes_preview(DONOR_1, project_id).contains(",recurring_monthly:2,"));
+}
+
+#[test]
+fn december_to_january_continues_streaks() {
+    let mut state = PhilanthrifyTestState::new();
+
+    state.world.current_block().block_timestamp(DEC_15_2025);
+    state.donate_to_project(DONOR_1, 100);
+    state.world.current_block().block_timestamp(JAN_15_2026);
+    state.donate_to_project(DONOR_1, 100);
+
+    let pattern = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_recurring_pattern(DONOR_1)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(pattern.monthly_streak, 2);
+    assert_eq!(pattern.quarterly_streak, 2);
+    assert_eq!(pattern.last_donation_month, 202601);
+    assert_eq!(pattern.last_donation_quarter, 20261);
+}
+
+#[test]
+fn streaks_are_tracked_per_entity() {
+    let mut state = PhilanthrifyTestState::new();
+    let charity_id = state.entity_id(CHARITY_NAME);
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    state.donate_to_charity(DONOR_1, 100);
+    state.world.current_block().block_timestamp(FEB_15_2026);
+    state.donate_to_project(DONOR_1, 100);
+
+    let global_pattern = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_recurring_pattern(DONOR_1)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(global_pattern.monthly_streak, 2);
+
+    let project_pattern = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_entity_recurring_pattern(DONOR_1, project_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(project_pattern.monthly_streak, 1);
+    assert_eq!(project_pattern.last_donation_month, 202602);
+
+    let charity_pattern = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_donor_entity_recurring_pattern(DONOR_1, charity_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(charity_pattern.last_donation_month, 202601);
+
+    // The charity gift does not lend the project badge a monthly streak
+    assert!(!state.attributes_preview(DONOR_1, project_id).contains("recurring_monthly"));
+}
+
+#[test]
+fn project_transactions_update_one_receipt() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+
+    let logs = state.transaction_for_project(500, "supplies", "gloves and bags");
+    assert!(has_event(&logs, "transaction_nft_minted"));
+    assert!(has_event(&logs, "transaction_event"));
+
+    state.transaction_for_project(300, "transport", "bus rental");
+
+    let receipt = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_transaction_nft_for_entity(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .expect("transaction receipt not minted");
+    assert_eq!(receipt.nft_nonce, 1);
+    assert_eq!(receipt.donation_count, 2);
+    assert_eq!(receipt.tier_level, 0);
+    assert_eq!(receipt.total_amount, BigUint::from(800u64));
+    assert_eq!(receipt.last_amount, BigUint::from(300u64));
+}
+
+#[test]
+fn per_transaction_receipts_build_spending_ledger() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_receipt_mode(project_id, factory_proxy::ReceiptMode::PerTransaction)
+        .run();
+
+    state.transaction_for_project(500, "supplies", "gloves and bags");
+    state.world.current_block().block_timestamp(FEB_15_2026);
+    state.transaction_for_project(300_000_000_000_000_000, "transport", "bus rental");
+    state.transaction_for_project(200, "supplies", "trash bags");
+
+    // No aggregated receipt; each expense has its own with its category and description
+    let aggregated = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_transaction_nft_for_entity(project_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(aggregated.into_option().is_none());
+
+    let ledger: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_records(project_id, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(ledger.len(), 3);
+    assert_eq!(ledger.iter().map(|record| record.id).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_ne!(ledger[0].receipt_nonce, ledger[1].receipt_nonce);
+    let receipt = state.nft_metadata(ledger[1].receipt_nonce);
+    assert_eq!(receipt.total_amount, BigUint::from(300_000_000_000_000_000u64));
+    assert_eq!(receipt.donation_count, 2);
+    let attributes = "tags:transaction2,spent$0.3,transport,Beach Day,receipt,philanthrify,project,transparency;traits:[\
+        {\"trait_type\":\"Type\",\"value\":\"Transaction\"},{\"trait_type\":\"Entity\",\"value\":\"Beach Day\"},\
+        {\"trait_type\":\"EntityType\",\"value\":\"project\"},{\"trait_type\":\"Amount\",\"value\":\"0.3 EGLD\"},\
+        {\"trait_type\":\"Category\",\"value\":\"transport\"},{\"trait_type\":\"Description\",\"value\":\"bus rental\"}]";
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, ledger[1].receipt_nonce, 1, attributes);
+
+    let supplies: Vec<u64> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_by_category(project_id, "supplies", 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|record| record.id)
+        .collect();
+    assert_eq!(supplies, vec![1, 3]);
+
+    let february: Vec<u64> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_in_time_range(project_id, FEB_15_2026, FEB_15_2026 + 86_400, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|record| record.id)
+        .collect();
+    assert_eq!(february, vec![2, 3]);
+}
+
+#[test]
+fn batch_transactions_update_receipt_once() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    state.transaction_for_project(500, "supplies", "gloves and bags");
+
+    let mut items = MultiValueEncoded::new();
+    for (amount, category, description) in [(300u64, "transport", "bus rental"), (200u64, "food", "lunch")] {
+        items.push(factory_proxy::TransactionItem {
+            display_amount: BigUint::from(amount),
+            category: ManagedBuffer::from(category),
+            description: ManagedBuffer::from(description),
+            user_image_uri: ManagedBuffer::new(),
+        });
+    }
+    let project = state.project.clone();
+    let logs = state
+        .world
+        .tx()
+        .from(&project)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .mint_transaction_nft_batch(OWNER, items)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "transaction_batch_recorded"));
+    assert!(!has_event(&logs, "transaction_nft_minted"));
+
+    let receipt = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_transaction_nft_for_entity(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .expect("transaction receipt not minted");
+    assert_eq!(receipt.nft_nonce, 1);
+    assert_eq!(receipt.donation_count, 3);
+    assert_eq!(receipt.total_amount, BigUint::from(1_000u64));
+    assert_eq!(receipt.last_amount, BigUint::from(200u64));
+}
+
+#[test]
+fn spending_categories_track_budget_vs_spent() {
+    let mut state = PhilanthrifyTestState::new();
+    let project = state.project.clone();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .set_spending_category("supplies", EgldOrEsdtTokenIdentifier::egld(), 600u64)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .set_spending_category("transport", EgldOrEsdtTokenIdentifier::egld(), 0u64)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .transaction_for_project(100u64, "catering", "lunch", ManagedBuffer::new())
+        .with_result(ExpectError(4, "Unknown spending category"))
+        .run();
+
+    state.transaction_for_project(500, "supplies", "gloves and bags");
+    state.transaction_for_project(900, "transport", "bus rental");
+    // Over budget is recorded with a warning by default
+    let logs = state.transaction_for_project(200, "supplies", "trash bags");
+    assert!(has_event(&logs, "budget_exceeded"));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .set_budget_policy(project_proxy::BudgetPolicy::Reject)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .transaction_for_project(1u64, "supplies", "tape", ManagedBuffer::new())
+        .with_result(ExpectError(4, "Category budget exceeded"))
+        .run();
+
+    let mut budgets: Vec<(String, u64, u64)> = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .get_category_budgets()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|entry| {
+            let category = String::from_utf8(entry.category.to_vec()).unwrap();
+            (category, entry.budget.to_u64().unwrap(), entry.spent.to_u64().unwrap())
+        })
+        .collect();
+    budgets.sort();
+    assert_eq!(budgets, vec![("supplies".to_string(), 600, 700), ("transport".to_string(), 0, 900)]);
+
+    // The EGLD budget does not cap USDC spending, which is tracked on its own
+    state.accept_usdc();
+    state.donate_usdc_to_project(DONOR_2, 5_000_000);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .paid_transaction_for_project(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID),
+            1_000_000u64,
+            DONOR_1,
+            "supplies",
+            "buckets",
+            ManagedBuffer::new(),
+        )
+        .run();
+    let usdc_spent = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .category_spent("supplies", EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(usdc_spent, BigUint::from(1_000_000u64));
+    let egld_spent = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .category_spent("supplies", EgldOrEsdtTokenIdentifier::egld())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(egld_spent, BigUint::from(700u64));
+}
+
+#[test]
+fn paid_spending_leaves_the_treasury() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    let project = state.project.clone();
+    state.donate_to_project(DONOR_1, 1_000);
+
+    let logs = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .paid_transaction_for_project(
+            EgldOrEsdtTokenIdentifier::egld(),
+            300u64,
+            DONOR_2,
+            "supplies",
+            "gloves and bags",
+            ManagedBuffer::new(),
+        )
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "spending_paid"));
+    state.world.check_account(DONOR_2).balance(1_000_300);
+    state.world.check_account(&project).balance(700);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .paid_transaction_for_project(
+            EgldOrEsdtTokenIdentifier::egld(),
+            800u64,
+            DONOR_2,
+            "supplies",
+            "more gloves",
+            ManagedBuffer::new(),
+        )
+        .with_result(ExpectError(4, "Insufficient available balance"))
+        .run();
+
+    // Received = withdrawn + spent + available
+    let treasury: Vec<_> = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .get_treasury()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|entry| entry.into_tuple())
+        .collect();
+    assert_eq!(treasury.len(), 1);
+    let (_, received, withdrawn, available) = &treasury[0];
+    assert_eq!((received.to_u64(), withdrawn.to_u64(), available.to_u64()), (Some(1_000), Some(0), Some(700)));
+    let spent = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .total_spent(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(spent, BigUint::from(300u64));
+
+    let ledger: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_records(project_id, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(ledger.len(), 1);
+    assert_eq!(ledger[0].recipient, DONOR_2.to_managed_address());
+    assert_eq!(ledger[0].amount, BigUint::from(300u64));
+
+    // USDC spending keeps its own total on the receipt, in its own decimals
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_amount_format(2u32, 2u32)
+        .run();
+    state.accept_usdc();
+    state.donate_usdc_to_project(DONOR_2, 5_000_000);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .paid_transaction_for_project(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID),
+            1_500_000u64,
+            DONOR_1,
+            "supplies",
+            "buckets",
+            ManagedBuffer::new(),
+        )
+        .run();
+    let receipt = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_transaction_nft_for_entity(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .expect("receipt minted");
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, receipt.nft_nonce, 1, "tags:transaction2,spent$3 EGLD+1.5 USDC,Beach Day,receipt,philanthrify,project,transparency,impact");
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .set_entity_receipt_mode(project_id, factory_proxy::ReceiptMode::PerTransaction)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .paid_transaction_for_project(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID),
+            500_000u64,
+            DONOR_1,
+            "supplies",
+            "mops",
+            ManagedBuffer::new(),
+        )
+        .run();
+    let ledger: Vec<_> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_records(project_id, 2usize, 1usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    let attributes = "tags:transaction3,spent$0.5 USDC,supplies,Beach Day,receipt,philanthrify,project,transparency;traits:[\
+        {\"trait_type\":\"Type\",\"value\":\"Transaction\"},{\"trait_type\":\"Entity\",\"value\":\"Beach Day\"},\
+        {\"trait_type\":\"EntityType\",\"value\":\"project\"},{\"trait_type\":\"Amount\",\"value\":\"0.5 USDC\"},\
+        {\"trait_type\":\"Category\",\"value\":\"supplies\"},{\"trait_type\":\"Description\",\"value\":\"mops\"}]";
+    state
+        .world
+        .check_account(FACTORY)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, ledger[0].receipt_nonce, 1, attributes);
+
+    // Display-only spending is reported apart, so the paid totals still match the treasury
+    state.transaction_for_project(100, "outreach", "flyers");
+    let display = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_entity_display_spending(project_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(display, BigUint::from(100u64));
+    let mut paid: Vec<(String, u64)> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_entity_paid_spending(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|total| (String::from_utf8(total.token_identifier.into_name().to_vec()).unwrap(), total.amount.to_u64().unwrap()))
+        .collect();
+    paid.sort();
+    assert_eq!(paid, vec![("EGLD".to_string(), 300), ("USDC-123456".to_string(), 2_000_000)]);
+    let spent = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .total_spent(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(spent, BigUint::from(300u64));
+}
+
+#[test]
+fn only_registered_entities_can_mint() {
+    let mut state = PhilanthrifyTestState::new();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .mint_nft(DONOR_1, EgldOrEsdtTokenIdentifier::egld(), 100u64, true, ManagedBuffer::new(), MultiValueEncoded::new())
+        .with_result(ExpectError(4, "Caller is not a registered entity"))
+        .run();
+}
+
+#[test]
+fn platform_pause_blocks_child_donations() {
+    let mut state = PhilanthrifyTestState::new();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .pause()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&state.project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .donate_to_project(ManagedBuffer::new(), MultiValueEncoded::new())
+        .egld(100)
+        .with_result(ExpectError(4, "Platform is paused"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .unpause()
+        .run();
+    state.donate_to_project(DONOR_1, 100);
+}
+
+#[test]
+fn charity_ownership_transfer_moves_project_control() {
+    let mut state = PhilanthrifyTestState::new();
+    let charity = state.charity.clone();
+    let project = state.project.clone();
+    state.donate_to_project(DONOR_1, 1_000);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .transfer_ownership(DONOR_2)
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .accept_ownership()
+        .run();
+
+    // The old charity owner loses the project with the charity
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 100u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 100u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .run();
+    state.world.check_account(DONOR_2).balance(1_000_100);
+    state.world.check_account(&project).balance(900);
+}
+
+#[test]
+fn project_ownership_transfer_locks_out_the_charity_owner() {
+    let mut state = PhilanthrifyTestState::new();
+    let project = state.project.clone();
+    state.donate_to_project(DONOR_1, 1_000);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .transfer_ownership(DONOR_2)
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .accept_ownership()
+        .run();
+
+    // The charity owner no longer stands in for the project owner
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 100u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .grant_role(project_proxy::Role::Treasurer, OWNER)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 100u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .run();
+    state.world.check_account(&project).balance(900);
+}
+
+#[test]
+fn esdt_donations_need_an_accepted_token() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    let project = state.project.clone();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .donate_to_project(ManagedBuffer::new(), MultiValueEncoded::new())
+        .single_esdt(&USDC_TOKEN_ID.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .with_result(ExpectError(4, "Token not accepted"))
+        .run();
+
+    // Only the owner or an editor manages the allowlist
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .add_accepted_token(USDC_TOKEN_ID)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state.accept_usdc();
+    state.donate_usdc_to_project(DONOR_2, 1_000_000);
+    state.world.check_account(&project).esdt_balance(USDC_TOKEN_ID, 1_000_000);
+    let badge_nonce = state.donor_nfts(DONOR_2, project_id)[0];
+    let badge = state.nft_metadata(badge_nonce);
+    assert_eq!(badge.last_token_identifier, EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID));
+    assert_eq!(badge.last_amount, BigUint::from(1_000_000u64));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .remove_accepted_token(USDC_TOKEN_ID)
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .donate_to_project(ManagedBuffer::new(), MultiValueEncoded::new())
+        .single_esdt(&USDC_TOKEN_ID.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .with_result(ExpectError(4, "Token not accepted"))
+        .run();
+}
+
+#[test]
+fn withdraw_is_limited_to_the_available_balance() {
+    let mut state = PhilanthrifyTestState::new();
+    let project = state.project.clone();
+    state.donate_to_project(DONOR_1, 1_000);
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 100u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    let logs = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 600u64, OptionalValue::Some(DONOR_2.to_managed_address()))
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "withdrawal_event"));
+    state.world.check_account(DONOR_2).balance(1_000_600);
+
+    let available = state
+        .world
+        .query()
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .get_available_balance(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(available, BigUint::from(400u64));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 401u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Insufficient available balance"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 0u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Amount must be > 0"))
+        .run();
+    state.world.check_account(&project).balance(400);
+}
+
+#[test]
+fn granted_roles_act_until_revoked() {
+    let mut state = PhilanthrifyTestState::new();
+    let charity = state.charity.clone();
+    state.donate_to_charity(DONOR_1, 1_000);
+
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .grant_role(charity_proxy::Role::Treasurer, PROCESSOR)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .grant_role(charity_proxy::Role::Treasurer, PROCESSOR)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .grant_role(charity_proxy::Role::Treasurer, PROCESSOR)
+        .with_result(ExpectError(4, "Address already has role"))
+        .run();
+
+    // A treasurer withdraws, but the role does not reach other areas such as the allowlist
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 300u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .run();
+    state.world.check_account(PROCESSOR).balance(300);
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .add_accepted_token(USDC_TOKEN_ID)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .revoke_role(charity_proxy::Role::Treasurer, PROCESSOR)
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .withdraw(EgldOrEsdtTokenIdentifier::egld(), 300u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .revoke_role(charity_proxy::Role::Treasurer, PROCESSOR)
+        .with_result(ExpectError(4, "Address does not have role"))
+        .run();
+}
+
+#[test]
+fn auditor_flags_spending_records() {
+    let mut state = PhilanthrifyTestState::new();
+    let project_id = state.entity_id(PROJECT_NAME);
+    let project = state.project.clone();
+    state.transaction_for_project(100, "Supplies", "Gloves");
+
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .flag_spending_record(project_id, 1u64, "No invoice")
+        .with_result(ExpectError(4, "Only global admin, platform admin or auditor allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .grant_role(factory_proxy::Role::Auditor, PROCESSOR)
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .flag_spending_record(project_id, 2u64, "No invoice")
+        .with_result(ExpectError(4, "Spending record not found"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .flag_spending_record(project_id, 1u64, "No invoice")
+        .run();
+
+    let flags: Vec<(u64, String)> = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_flags(project_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|flag| (flag.record_id, flag.reason.to_string()))
+        .collect();
+    assert_eq!(flags, vec![(1, "No invoice".to_string())]);
+
+    // The auditor only reviews; moving funds still needs the entity's own roles
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(&project)
+        .typed(project_proxy::PhilanthrifyProjectProxy)
+        .transaction_for_project(100u64, "Supplies", "Gloves", ManagedBuffer::new())
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .clear_spending_flag(project_id, 1u64)
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROCESSOR)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .clear_spending_flag(project_id, 1u64)
+        .with_result(ExpectError(4, "Spending record not flagged"))
+        .run();
+    let flags = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_spending_flags(project_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(flags.len(), 0);
+}
+
+#[test]
+fn ownership_transfer_needs_acceptance_and_can_be_cancelled() {
+    let mut state = PhilanthrifyTestState::new();
+    let charity = state.charity.clone();
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .transfer_ownership(DONOR_1)
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .accept_ownership()
+        .with_result(ExpectError(4, "No pending owner"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .transfer_ownership(DONOR_1)
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_2)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .accept_ownership()
+        .with_result(ExpectError(4, "Only pending owner allowed"))
+        .run();
+
+    // Cancelling withdraws the offer
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .cancel_ownership_transfer()
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .accept_ownership()
+        .with_result(ExpectError(4, "No pending owner"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .transfer_ownership(DONOR_1)
+        .run();
+    // Until accepted the current owner keeps control
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .pause()
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .accept_ownership()
+        .run();
+
+    let owner = state
+        .world
+        .query()
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .owner()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(owner, DONOR_1.to_managed_address());
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .unpause()
+        .with_result(ExpectError(4, "Only owner allowed"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(&charity)
+        .typed(charity_proxy::PhilanthrifyCharityProxy)
+        .unpause()
+        .run();
+}
+
+#[test]
+fn backfill_indexes_badges_minted_before_the_donor_index() {
+    use philanthrify_factory::PhilanthrifyFactory;
+
+    let mut state = PhilanthrifyTestState::new();
+    state.donate_to_project(DONOR_1, 100);
+    state.transaction_for_project(50, "supplies", "gloves");
+    state.donate_to_charity(DONOR_1, 100);
+
+    // Badges from before the index existed are missing from it
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .whitebox(philanthrify_factory::contract_obj, |sc| {
+            sc.donor_badges(&DONOR_1.to_managed_address()).clear();
+        });
+    assert_eq!(state.badge_locations(DONOR_1), (vec![], vec![]));
+
+    state
+        .world
+        .tx()
+        .from(DONOR_1)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .backfill_donor_badges(1u64, 10u64)
+        .with_result(ExpectError(4, "Only global admin, platform admin or NFT manager allowed"))
+        .run();
+
+    // The receipt in between is skipped, and a second run adds nothing
+    let added = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .backfill_donor_badges(1u64, 10u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(added, 2);
+    let (mut held, withdrawn) = state.badge_locations(DONOR_1);
+    held.sort();
+    assert_eq!((held, withdrawn), (vec![1, 3], vec![]));
+    let added = state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .backfill_donor_badges(1u64, 10u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(added, 0);
+}
+
+#[test]
+fn charity_with_projects_cannot_be_unregistered() {
+    let mut state = PhilanthrifyTestState::new();
+    let charity = state.charity.clone();
+    let project = state.project.clone();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .unregister_entity(&charity)
+        .with_result(ExpectError(4, "Unregister the charity's projects first"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .unregister_entity(&project)
+        .run();
+    let projects = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .get_projects_of_charity(&charity, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(projects.len(), 0);
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .unregister_entity(&charity)
+        .run();
+    let registered = state
+        .world
+        .query()
+        .to(FACTORY)
+        .typed(factory_proxy::PhilanthrifyFactoryProxy)
+        .is_registered_entity(&charity)
+        .returns(ReturnsResult)
+        .run();
+    assert!(!registered);
+}
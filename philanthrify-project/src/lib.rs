@@ -5,6 +5,36 @@ extern crate alloc;
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod factory_proxy;
+
+const MAX_BATCH_DONATIONS: usize = 100;
+const GAS_PER_BATCH_DONATION: u64 = 5_000_000;  // Rough cost of one badge mint or update on the factory
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Treasurer,  // Withdrawals and spending transactions
+    Editor,     // Accepted tokens and donation settings
+    Processor,  // Settles batched off-chain donations
+}
+
+/// What happens when spending takes a category over its budget.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    Warn,    // Record it and emit budget_exceeded (default)
+    Reject,  // Fail the transaction
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct CategoryBudget<M: ManagedTypeApi> {
+    pub category: ManagedBuffer<M>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,  // The budget's token; spent is in this token too
+    pub budget: BigUint<M>,  // 0 = no cap
+    pub spent: BigUint<M>,
+}
+
 #[multiversx_sc::contract]
 pub trait PhilanthrifyProject {
     #[init]
@@ -13,12 +43,10 @@ pub trait PhilanthrifyProject {
         project_name: ManagedBuffer,
         charity_address: ManagedAddress,
         factory_address: ManagedAddress,
-        global_admin: ManagedAddress,
     ) {
         self.project_name().set(&project_name);
         self.charity_address().set(&charity_address);
         self.factory_address().set(&factory_address);
-        self.global_admin().set(&global_admin);
         self.owner().set(&charity_address);
     }
 
@@ -27,7 +55,7 @@ pub trait PhilanthrifyProject {
         let caller = self.blockchain().get_caller();
         let owner = self.owner().get();
         let factory = self.factory_address().get();
-        let admin = self.global_admin().get();
+        let admin = self.get_global_admin();
 
         require!(
             caller == owner || caller == factory || caller == admin,
@@ -36,21 +64,75 @@ pub trait PhilanthrifyProject {
     }
 
     fn only_owner(&self) {
-        // Project owner is the charity contract address
-        // But we need to allow the charity owner (admin) to call transactionForProject
-        // So check if caller is either:
-        // 1. The charity contract itself (owner)
-        // 2. The global admin (who is the charity owner)
+        // While the charity contract still owns the project, whoever owns the charity right now
+        // (getGlobalAdmin) acts for it; once ownership is transferred only the new owner does
         let caller = self.blockchain().get_caller();
-        let owner = self.owner().get(); // This is the charity contract address
-        let admin = self.global_admin().get(); // This is the charity owner (admin)
-        
+        let owner = self.owner().get();
         require!(
-            caller == owner || caller == admin,
+            caller == owner || (owner == self.charity_address().get() && caller == self.get_global_admin()),
             "Only owner allowed"
         );
     }
 
+    fn only_owner_or_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        if self.role_members(role).contains(&caller) {
+            return;
+        }
+        self.only_owner();
+    }
+
+    // ============================================================
+    // PAUSE - Local pause, plus the factory's global pause
+    // ============================================================
+
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.only_owner();
+        require!(!self.paused().get(), "Already paused");
+        self.paused().set(true);
+        self.paused_event(&self.blockchain().get_caller());
+    }
+
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.only_owner();
+        require!(self.paused().get(), "Not paused");
+        self.paused().set(false);
+        self.unpaused_event(&self.blockchain().get_caller());
+    }
+
+    /// Checks the factory flag directly so a platform-wide pause fails here before any cross-contract call.
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Project is paused");
+        let factory = self.factory_address().get();
+        require!(!self.factory_paused(factory).get(), "Platform is paused");
+    }
+
+    // ============================================================
+    // ROLES
+    // ============================================================
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.only_owner();
+        require!(!address.is_zero(), "Invalid address");
+        require!(self.role_members(role).insert(address.clone()), "Address already has role");
+        self.role_granted(role, &address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.only_owner();
+        require!(self.role_members(role).swap_remove(&address), "Address does not have role");
+        self.role_revoked(role, &address);
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        self.role_members(role).contains(&address)
+    }
+
     // ============================================================
     // TRANSACTION - MINTS NFT TO TRACK PROJECT SPENDING
     // ============================================================
@@ -62,10 +144,12 @@ pub trait PhilanthrifyProject {
         description: ManagedBuffer,
         user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
     ) {
-        self.only_owner();
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Treasurer);
 
         // 0 EGLD - only gas fees paid. display_amount is for NFT display only
         require!(display_amount > BigUint::zero(), "Display amount must be > 0");
+        self.record_category_spending(&category, &EgldOrEsdtTokenIdentifier::egld(), &display_amount);
 
         let project_name = self.project_name().get();
         // Use the caller (admin) as the entity owner for the NFT, not the project owner (charity contract)
@@ -76,19 +160,47 @@ pub trait PhilanthrifyProject {
         // Pass caller (admin) as entity_owner since admin is calling this
         self.tx()
             .to(&factory)
-            .raw_call("mintTransactionNft")
-            .argument(&caller)
-            .argument(&display_amount)  // Display amount only
-            .argument(&project_name)
-            .argument(&ManagedBuffer::from("project"))
-            .argument(&category)
-            .argument(&description)
-            .argument(&user_image_uri)  // Empty string if not provided
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_transaction_nft(&caller, &display_amount, &category, &description, &user_image_uri)
             .sync_call();
 
         self.transaction_event(&project_name, &display_amount, &category, &description);
     }
 
+    /// Pays a supplier or beneficiary from the treasury and mints the spending receipt,
+    /// so the spending ledger matches money that actually left the project.
+    #[endpoint(paidTransactionForProject)]
+    fn paid_transaction_for_project(
+        &self,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        recipient: ManagedAddress,
+        category: ManagedBuffer,
+        description: ManagedBuffer,
+        user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
+    ) {
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Treasurer);
+        require!(amount > 0u32, "Amount must be > 0");
+        require!(!recipient.is_zero(), "Invalid recipient address");
+        require!(amount <= self.get_available_balance(token_identifier.clone()), "Insufficient available balance");
+        self.record_category_spending(&category, &token_identifier, &amount);
+        self.total_spent(&token_identifier).update(|total| *total += &amount);
+
+        let project_name = self.project_name().get();
+        let caller = self.blockchain().get_caller();
+        let factory = self.factory_address().get();
+        self.tx()
+            .to(&factory)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_paid_transaction_nft(&caller, &token_identifier, &amount, &recipient, &category, &description, &user_image_uri)
+            .sync_call();
+
+        self.tx().to(&recipient).egld_or_single_esdt(&token_identifier, 0, &amount).transfer();
+        self.transaction_event(&project_name, &amount, &category, &description);
+        self.spending_paid_event(&recipient, &token_identifier, &amount, &category);
+    }
+
     #[allow_multiple_var_args]
     #[endpoint(batchTransactionForProject)]
     fn batch_transaction_for_project(
@@ -98,7 +210,8 @@ pub trait PhilanthrifyProject {
         categories: MultiValueEncoded<ManagedBuffer>,
         descriptions: MultiValueEncoded<ManagedBuffer>,
     ) {
-        self.only_owner();
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Treasurer);
         require!(num_transactions > 0, "Number of transactions must be > 0");
         
         let mut amounts_vec: ManagedVec<Self::Api, BigUint> = ManagedVec::new();
@@ -127,102 +240,294 @@ pub trait PhilanthrifyProject {
         let caller = self.blockchain().get_caller();
         let factory = self.factory_address().get();
 
+        let mut items: MultiValueEncoded<factory_proxy::TransactionItem<Self::Api>> = MultiValueEncoded::new();
         for i in 0..num_transactions {
             let display_amount = amounts_vec.get(i as usize);
             let category = categories_vec.get(i as usize);
             let description = descriptions_vec.get(i as usize);
             
             require!(*display_amount > BigUint::zero(), "Display amount must be > 0");
+            self.record_category_spending(&category, &EgldOrEsdtTokenIdentifier::egld(), &display_amount);
             
-            self.tx()
-                .to(&factory)
-                .raw_call("mintTransactionNft")
-                .argument(&caller)
-                .argument(&display_amount)
-                .argument(&project_name)
-                .argument(&ManagedBuffer::from("project"))
-                .argument(&category)
-                .argument(&description)
-                .argument(&ManagedBuffer::new())  // Empty string for batch (no user image)
-                .sync_call();
-
             self.batch_transaction_event(&project_name, i + 1, num_transactions, &display_amount, &category, &description);
+            items.push(factory_proxy::TransactionItem {
+                display_amount: display_amount.clone(),
+                category: category.clone(),
+                description: description.clone(),
+                user_image_uri: ManagedBuffer::new(),  // No user image in batch
+            });
+        }
+
+        // One factory call updates the receipt once for the whole batch
+        self.tx()
+            .to(&factory)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_transaction_nft_batch(&caller, items)
+            .sync_call();
+    }
+
+    // ============================================================
+    // SPENDING CATEGORIES - Optional catalogue with budget caps
+    // ============================================================
+
+    /// Adds a category to the catalogue or changes its budget (0 = no cap). The budget only caps
+    /// spending in `token_identifier` (display-only spending counts as EGLD); other tokens are tracked uncapped.
+    /// Once the catalogue has entries, spending must use one of them.
+    #[endpoint(setSpendingCategory)]
+    fn set_spending_category(&self, category: ManagedBuffer, token_identifier: EgldOrEsdtTokenIdentifier, budget: BigUint) {
+        self.only_owner();
+        require!(!category.is_empty(), "Category cannot be empty");
+        require!(token_identifier.is_valid(), "Invalid token identifier");
+        self.spending_categories().insert(category.clone());
+        self.category_budget_token(&category).set(&token_identifier);
+        self.category_budget(&category).set(&budget);
+    }
+
+    /// Running totals are kept in case the category is added back.
+    #[endpoint(removeSpendingCategory)]
+    fn remove_spending_category(&self, category: ManagedBuffer) {
+        self.only_owner();
+        require!(self.spending_categories().swap_remove(&category), "Unknown spending category");
+        self.category_budget(&category).clear();
+        self.category_budget_token(&category).clear();
+    }
+
+    #[endpoint(setBudgetPolicy)]
+    fn set_budget_policy(&self, policy: BudgetPolicy) {
+        self.only_owner();
+        self.budget_policy().set(policy);
+    }
+
+    /// Budget vs spent, in the budget's token, for every catalogue category.
+    #[view(getCategoryBudgets)]
+    fn get_category_budgets(&self) -> MultiValueEncoded<CategoryBudget<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for category in self.spending_categories().iter() {
+            let token_identifier = self.category_budget_token(&category).get();
+            let budget = self.category_budget(&category).get();
+            let spent = self.category_spent(&category, &token_identifier).get();
+            result.push(CategoryBudget { category, token_identifier, budget, spent });
         }
+        result
+    }
+
+    fn record_category_spending(&self, category: &ManagedBuffer, token_identifier: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let categories = self.spending_categories();
+        require!(categories.is_empty() || categories.contains(category), "Unknown spending category");
+
+        let spent = self.category_spent(category, token_identifier).get() + amount;
+        let budget = self.category_budget(category).get();
+        if budget > 0u32 && spent > budget && self.category_budget_token(category).get() == *token_identifier {
+            require!(self.budget_policy().get() == BudgetPolicy::Warn, "Category budget exceeded");
+            self.budget_exceeded_event(category, &budget, &spent);
+        }
+        self.category_spent(category, token_identifier).set(&spent);
     }
 
     // ============================================================
     // DONATION FUNCTIONS
     // ============================================================
 
+    #[payable("*")]
     #[endpoint(donateToProject)]
     fn donate_to_project(
         &self,
-        display_amount: BigUint,
         user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
         custom_tags: MultiValueEncoded<ManagedBuffer>,
     ) {
-        // 0 EGLD - only gas fees paid. display_amount is for NFT display only
+        self.require_not_paused();
+        // Real donation: EGLD or an accepted ESDT stays in the project treasury until the owner withdraws it
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0u32, "Donation amount must be > 0");
+        require!(payment.token_nonce == 0, "Only fungible tokens can be donated");
+        if let Some(token_id) = payment.token_identifier.as_esdt_option() {
+            require!(self.accepted_tokens().contains(&token_id), "Token not accepted");
+        }
+
         let caller = self.blockchain().get_caller();
+        self.received_tokens().insert(payment.token_identifier.clone());
+        self.total_received(&payment.token_identifier).update(|total| *total += &payment.amount);
+
+        self.mint_donation_nft(&caller, &payment.token_identifier, &payment.amount, false, &user_image_uri, custom_tags);
+    }
+
+    /// Off-chain (fiat) donations recorded for `donor`: no value moves, display_amount is for NFT display only.
+    /// Disabled unless the owner opts in via setDisplayOnlyDonations; only the owner or a processor may record them.
+    #[endpoint(donateDisplayOnly)]
+    fn donate_display_only(
+        &self,
+        donor: ManagedAddress,
+        display_amount: BigUint,
+        user_image_uri: ManagedBuffer,  // Optional user image (CID or full URL) - empty string means no image
+        custom_tags: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Processor);
+        require!(self.display_only_donations_enabled().get(), "Display-only donations are disabled");
+        require!(display_amount > 0u32, "Display amount must be > 0");
+
+        self.mint_donation_nft(&donor, &EgldOrEsdtTokenIdentifier::egld(), &display_amount, true, &user_image_uri, custom_tags);
+    }
+
+    fn mint_donation_nft(
+        &self,
+        donor: &ManagedAddress,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+        is_display_only: bool,
+        user_image_uri: &ManagedBuffer,
+        custom_tags: MultiValueEncoded<ManagedBuffer>,
+    ) {
         let factory = self.factory_address().get();
         let project_name = self.project_name().get();
 
-        let mut call = self.tx()
+        self.tx()
             .to(&factory)
-            .raw_call("mintNft")
-            .argument(&caller)
-            .argument(&display_amount)  // Display amount only
-            .argument(&project_name)
-            .argument(&ManagedBuffer::from("project"))
-            .argument(&user_image_uri);  // user_image_uri before custom_tags
-
-        for tag in custom_tags.into_iter() {
-            call = call.argument(&tag);
-        }
-
-        call.sync_call();
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_nft(donor, token_identifier, amount, is_display_only, user_image_uri, custom_tags)
+            .sync_call();
 
-        self.donation_event(&caller, &display_amount, &project_name);
+        self.donation_event(donor, amount, &project_name);
     }
 
+    /// Settles off-chain (fiat) donations for many donors in one factory call; amounts are for NFT display only.
     #[endpoint(batchDonateToProject)]
-    fn batch_donate_to_project(&self, num_donations: u64, display_amount_per_donation: BigUint, custom_tags: MultiValueEncoded<ManagedBuffer>) {
-        // 0 EGLD - only gas fees paid. display_amount is for NFT display only
-        require!(num_donations > 0 && num_donations <= 100, "Batch must be 1-100");
-        require!(display_amount_per_donation > BigUint::zero(), "Display amount must be > 0");
+    fn batch_donate_to_project(&self, donations: MultiValueEncoded<factory_proxy::DonationItem<Self::Api>>) {
+        self.require_not_paused();
+        self.only_owner_or_role(Role::Processor);
+        require!(self.display_only_donations_enabled().get(), "Display-only donations are disabled");
+        let donation_count = donations.len();
+        require!(donation_count > 0 && donation_count <= MAX_BATCH_DONATIONS, "Batch must be 1-100");
+        require!(
+            self.blockchain().get_gas_left() >= donation_count as u64 * GAS_PER_BATCH_DONATION,
+            "Not enough gas for batch size"
+        );
+
+        let items = donations.to_vec();
+        let mut total_amount = BigUint::zero();
+        for item in items.iter() {
+            require!(item.amount > 0u32, "Display amount must be > 0");
+            total_amount += &item.amount;
+        }
 
         let caller = self.blockchain().get_caller();
         let factory = self.factory_address().get();
         let project_name = self.project_name().get();
 
-        let mut tags_vec: ManagedVec<Self::Api, ManagedBuffer> = ManagedVec::new();
-        for tag in custom_tags.into_iter() {
-            tags_vec.push(tag);
+        self.tx()
+            .to(&factory)
+            .typed(factory_proxy::PhilanthrifyFactoryProxy)
+            .mint_nft_batch(EgldOrEsdtTokenIdentifier::egld(), true, donations)
+            .sync_call();
+
+        for item in items.iter() {
+            self.donation_event(&item.donor, &item.amount, &project_name);
         }
+        self.batch_event(&caller, donation_count, &total_amount, &project_name);
+    }
+
+    #[endpoint(addAcceptedToken)]
+    fn add_accepted_token(&self, token_id: TokenIdentifier) {
+        self.only_owner_or_role(Role::Editor);
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
+        self.accepted_tokens().insert(token_id);
+    }
 
-        for i in 0..num_donations {
-            let mut call = self.tx()
-                .to(&factory)
-                .raw_call("mintNft")
-                .argument(&caller)
-                .argument(&display_amount_per_donation)  // Display amount only
-                .argument(&project_name)
-                .argument(&ManagedBuffer::from("project"));
+    #[endpoint(removeAcceptedToken)]
+    fn remove_accepted_token(&self, token_id: TokenIdentifier) {
+        self.only_owner_or_role(Role::Editor);
+        self.accepted_tokens().swap_remove(&token_id);
+    }
 
-            for tag in tags_vec.iter() {
-                call = call.argument(&tag);
-            }
+    #[endpoint(setDisplayOnlyDonations)]
+    fn set_display_only_donations(&self, enabled: bool) {
+        self.only_owner_or_role(Role::Editor);
+        self.display_only_donations_enabled().set(enabled);
+    }
 
-            call.sync_call();
+    // ============================================================
+    // TREASURY - Owner withdraws received donations
+    // ============================================================
 
-            self.batch_event(&caller, i + 1, num_donations, &display_amount_per_donation, &project_name);
+    #[endpoint(withdraw)]
+    fn withdraw(
+        &self,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        opt_to: OptionalValue<ManagedAddress>,
+    ) {
+        self.only_owner_or_role(Role::Treasurer);
+        require!(amount > 0u32, "Amount must be > 0");
+        require!(amount <= self.get_available_balance(token_identifier.clone()), "Insufficient available balance");
+
+        let to = match opt_to {
+            OptionalValue::Some(address) => address,
+            OptionalValue::None => self.blockchain().get_caller(),
+        };
+        require!(!to.is_zero(), "Invalid recipient address");
+
+        self.total_withdrawn(&token_identifier).update(|total| *total += &amount);
+        self.tx().to(&to).egld_or_single_esdt(&token_identifier, 0, &amount).transfer();
+
+        self.withdrawal_event(&to, &token_identifier, &amount);
+    }
+
+    /// Received minus withdrawn minus paid out as spending.
+    #[view(getAvailableBalance)]
+    fn get_available_balance(&self, token_identifier: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.total_received(&token_identifier).get()
+            - self.total_withdrawn(&token_identifier).get()
+            - self.total_spent(&token_identifier).get()
+    }
+
+    /// Per-token accounting for every token ever donated: (token, received, withdrawn, available).
+    /// Available is also net of spending; see getTotalSpent.
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> MultiValueEncoded<MultiValue4<EgldOrEsdtTokenIdentifier, BigUint, BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_identifier in self.received_tokens().iter() {
+            let received = self.total_received(&token_identifier).get();
+            let withdrawn = self.total_withdrawn(&token_identifier).get();
+            let available = self.get_available_balance(token_identifier.clone());
+            result.push((token_identifier, received, withdrawn, available).into());
         }
+        result
     }
 
-    #[endpoint(setOwner)]
-    fn set_owner(&self, new_owner: ManagedAddress) {
+    // ============================================================
+    // OWNERSHIP TRANSFER - Propose, then the new owner accepts
+    // ============================================================
+
+    #[endpoint(transferOwnership)]
+    fn transfer_ownership(&self, new_owner: ManagedAddress) {
         self.only_owner();
         require!(!new_owner.is_zero(), "Invalid owner address");
+        let current_owner = self.owner().get();
+        require!(new_owner != current_owner, "Address is already owner");
+
+        self.pending_owner().set(&new_owner);
+        self.ownership_transfer_started(&current_owner, &new_owner);
+    }
+
+    #[endpoint(acceptOwnership)]
+    fn accept_ownership(&self) {
+        let pending_mapper = self.pending_owner();
+        require!(!pending_mapper.is_empty(), "No pending owner");
+        let new_owner = pending_mapper.take();
+        require!(self.blockchain().get_caller() == new_owner, "Only pending owner allowed");
+
+        let previous_owner = self.owner().get();
         self.owner().set(&new_owner);
+        self.ownership_transferred(&previous_owner, &new_owner);
+    }
+
+    #[endpoint(cancelOwnershipTransfer)]
+    fn cancel_ownership_transfer(&self) {
+        self.only_owner();
+        let pending_mapper = self.pending_owner();
+        require!(!pending_mapper.is_empty(), "No pending owner");
+        let pending_owner = pending_mapper.take();
+        self.ownership_transfer_cancelled(&pending_owner);
     }
 
     // ============================================================
@@ -246,16 +551,61 @@ pub trait PhilanthrifyProject {
         #[indexed] entity: &ManagedBuffer,
     );
 
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("unpaused")]
+    fn unpaused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("ownership_transfer_started")]
+    fn ownership_transfer_started(&self, #[indexed] current: &ManagedAddress, #[indexed] pending: &ManagedAddress);
+
+    #[event("ownership_transfer_cancelled")]
+    fn ownership_transfer_cancelled(&self, #[indexed] pending: &ManagedAddress);
+
+    #[event("ownership_transferred")]
+    fn ownership_transferred(&self, #[indexed] previous: &ManagedAddress, #[indexed] new: &ManagedAddress);
+
+    #[event("role_granted")]
+    fn role_granted(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("withdrawal_event")]
+    fn withdrawal_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
     #[event("batch_event")]
     fn batch_event(
         &self,
         #[indexed] caller: &ManagedAddress,
-        #[indexed] current: u64,
-        #[indexed] total: u64,
-        #[indexed] amount_per: &BigUint,
+        #[indexed] donation_count: usize,
+        #[indexed] total_amount: &BigUint,
         #[indexed] entity: &ManagedBuffer,
     );
 
+    #[event("spending_paid")]
+    fn spending_paid_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] category: &ManagedBuffer,
+    );
+
+    #[event("budget_exceeded")]
+    fn budget_exceeded_event(
+        &self,
+        #[indexed] category: &ManagedBuffer,
+        #[indexed] budget: &BigUint,
+        #[indexed] spent: &BigUint,
+    );
+
     #[event("batch_transaction_event")]
     fn batch_transaction_event(
         &self,
@@ -283,11 +633,72 @@ pub trait PhilanthrifyProject {
     #[storage_mapper("factory_address")]
     fn factory_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// The charity's current owner, read from the charity so a charity ownership transfer moves project control too
     #[view(getGlobalAdmin)]
-    #[storage_mapper("global_admin")]
-    fn global_admin(&self) -> SingleValueMapper<ManagedAddress>;
+    fn get_global_admin(&self) -> ManagedAddress {
+        self.charity_owner(self.charity_address().get()).get()
+    }
+
+    #[storage_mapper_from_address("owner")]
+    fn charity_owner(&self, charity_address: ManagedAddress) -> SingleValueMapper<ManagedAddress, ManagedAddress>;
 
     #[view(getOwner)]
     #[storage_mapper("owner")]
     fn owner(&self) -> SingleValueMapper<ManagedAddress>;
-}
\ No newline at end of file
+
+    #[view(getPendingOwner)]
+    #[storage_mapper("pending_owner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper_from_address("paused")]
+    fn factory_paused(&self, factory_address: ManagedAddress) -> SingleValueMapper<bool, ManagedAddress>;
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getAcceptedTokens)]
+    #[storage_mapper("accepted_tokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[storage_mapper("received_tokens")]
+    fn received_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getTotalReceived)]
+    #[storage_mapper("total_received")]
+    fn total_received(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalWithdrawn)]
+    #[storage_mapper("total_withdrawn")]
+    fn total_withdrawn(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalSpent)]
+    #[storage_mapper("total_spent")]
+    fn total_spent(&self, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(isDisplayOnlyDonationsEnabled)]
+    #[storage_mapper("display_only_donations_enabled")]
+    fn display_only_donations_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getSpendingCategories)]
+    #[storage_mapper("spending_categories")]
+    fn spending_categories(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("category_budget")]
+    fn category_budget(&self, category: &ManagedBuffer) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("category_budget_token")]
+    fn category_budget_token(&self, category: &ManagedBuffer) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getCategorySpent)]
+    #[storage_mapper("category_spent")]
+    fn category_spent(&self, category: &ManagedBuffer, token_identifier: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getBudgetPolicy)]
+    #[storage_mapper("budget_policy")]
+    fn budget_policy(&self) -> SingleValueMapper<BudgetPolicy>;
+}